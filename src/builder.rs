@@ -0,0 +1,126 @@
+//! Builder module.
+//!
+//! Implements incremental construction of UTC datetimes from individual components.
+
+use crate::date::{DateComponent, UTCDate};
+use crate::time::UTCTimeOfDay;
+use crate::{UTCDatetime, UTCDatetimeError};
+
+/// UTC Datetime Builder.
+///
+/// Builds a [UTCDatetime] from individually provided components,
+/// eg. from separately parsed fields.
+/// The date components (year, month and day) are required,
+/// unset time components default to zero.
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::builder::UTCDatetimeBuilder;
+/// use utc_dt::UTCDatetime;
+///
+/// let datetime = UTCDatetimeBuilder::new()
+///     .year(2023)
+///     .month(6)
+///     .day(15)
+///     .hour(10)
+///     .build()
+///     .unwrap();
+/// assert_eq!(datetime, UTCDatetime::try_from_iso_datetime("2023-06-15T10:00:00Z").unwrap());
+/// // date components are required
+/// assert!(UTCDatetimeBuilder::new().month(6).day(15).build().is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UTCDatetimeBuilder {
+    year: Option<u64>,
+    month: Option<u8>,
+    day: Option<u8>,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    subsec_ns: u32,
+}
+
+impl UTCDatetimeBuilder {
+    /// Create a builder with no components set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            year: None,
+            month: None,
+            day: None,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            subsec_ns: 0,
+        }
+    }
+
+    /// Set the year
+    #[must_use]
+    pub const fn year(mut self, year: u64) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Set the month, `[1, 12]`
+    #[must_use]
+    pub const fn month(mut self, month: u8) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Set the day of the month, `[1, 31]`
+    #[must_use]
+    pub const fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Set the hour, defaults to zero
+    #[must_use]
+    pub const fn hour(mut self, hour: u8) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Set the minute, defaults to zero
+    #[must_use]
+    pub const fn minute(mut self, minute: u8) -> Self {
+        self.minute = minute;
+        self
+    }
+
+    /// Set the second, defaults to zero
+    #[must_use]
+    pub const fn second(mut self, second: u8) -> Self {
+        self.second = second;
+        self
+    }
+
+    /// Set the subsecond nanoseconds, defaults to zero
+    #[must_use]
+    pub const fn subsec_ns(mut self, subsec_ns: u32) -> Self {
+        self.subsec_ns = subsec_ns;
+        self
+    }
+
+    /// Try to build the datetime from the provided components.
+    ///
+    /// Time components are validated as per [UTCTimeOfDay::try_from_hhmmss].
+    pub fn build(&self) -> Result<UTCDatetime, UTCDatetimeError> {
+        let year = self
+            .year
+            .ok_or(UTCDatetimeError::MissingComponent(DateComponent::Year))?;
+        let month = self
+            .month
+            .ok_or(UTCDatetimeError::MissingComponent(DateComponent::Month))?;
+        let day = self
+            .day
+            .ok_or(UTCDatetimeError::MissingComponent(DateComponent::Day))?;
+        let date = UTCDate::try_from_components(year, month, day)?;
+        let tod =
+            UTCTimeOfDay::try_from_hhmmss(self.hour, self.minute, self.second, self.subsec_ns)?;
+        Ok(UTCDatetime::from_components(date, tod))
+    }
+}
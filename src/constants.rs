@@ -27,4 +27,10 @@
 /** Microseconds per millisecond*/ pub const MICROS_PER_MILLI: u64 = 1000;
 /** Nanoseconds per millisecond */ pub const NANOS_PER_MILLI: u64 = MICROS_PER_MILLI * 1000;
 
-/** Nanoseconds per microsecond */ pub const NANOS_PER_MICRO: u64 = 1000;
\ No newline at end of file
+/** Nanoseconds per microsecond */ pub const NANOS_PER_MICRO: u64 = 1000;
+
+/** Cumulative days before each zero-based month of a common year, ending with the year length */
+pub const DAYS_BEFORE_MONTH: [u16; 13] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365];
+/** Cumulative days before each zero-based month of a leap year, ending with the year length */
+pub const DAYS_BEFORE_MONTH_LEAP: [u16; 13] =
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335, 366];
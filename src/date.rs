@@ -4,15 +4,21 @@
 //! proleptic Gregorian Calendar (the *civil* calendar),
 //! to create UTC dates.
 
-use crate::time::{UTCDay, UTCTimestamp, UTCTransformations};
+use crate::constants::{DAYS_BEFORE_MONTH, DAYS_BEFORE_MONTH_LEAP};
+use crate::time::{UTCDay, UTCTimestamp, UTCTransformations, Weekday};
 use crate::util::StrWriter;
+use crate::IsoStr;
+use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt::{Display, Formatter, Write};
-use core::num::ParseIntError;
+use core::num::{IntErrorKind, NonZeroU8, ParseIntError};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration;
 
 #[cfg(feature = "alloc")]
 use alloc::{format, string::String};
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
 
 /// UTC Date.
 ///
@@ -57,7 +63,12 @@ use alloc::{format, string::String};
 /// Unchecked methods are provided for use in hot paths requiring high levels of optimisation.
 /// These methods assume valid input.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UTCDate {
     era: u32,
     yoe: u16,
@@ -65,6 +76,22 @@ pub struct UTCDate {
     day: u8,
 }
 
+impl PartialOrd for UTCDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UTCDate {
+    /// Chronological ordering of dates.
+    ///
+    /// The internal era and year-of-era begin in March, so are not
+    /// ordered chronologically for January and February dates.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_components().cmp(&other.as_components())
+    }
+}
+
 impl Default for UTCDate {
     fn default() -> Self {
         Self::MIN
@@ -74,7 +101,30 @@ impl Default for UTCDate {
 impl Display for UTCDate {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let (year, month, day) = self.as_components();
-        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+        if year > Self::MAX_BASIC_YEAR {
+            // ISO 8601 expanded year representation
+            write!(f, "+{}-{:02}-{:02}", year, month, day)
+        } else {
+            write!(f, "{:04}-{:02}-{:02}", year, month, day)
+        }
+    }
+}
+
+impl PartialEq<str> for UTCDate {
+    /// Compares the ISO date representation (see [UTCDate::write_iso_date]) with the str,
+    /// without allocation.
+    fn eq(&self, other: &str) -> bool {
+        let mut buf = [0; UTCDate::MAX_ISO_DATE_LEN];
+        match self.write_iso_date(&mut buf) {
+            Ok(written) => buf[..written] == *other.as_bytes(),
+            Err(_) => false,
+        }
+    }
+}
+
+impl PartialEq<&str> for UTCDate {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
     }
 }
 
@@ -89,6 +139,11 @@ impl UTCDate {
         day: 1,
     };
 
+    /// The UTC Date of the Unix Epoch, Jan 1, 1970.
+    ///
+    /// Equal to [UTCDate::MIN].
+    pub const EPOCH: Self = Self::MIN;
+
     /// The maximum UTC Date supported.
     ///
     /// Equal to `November 9, 584_554_051_223`
@@ -109,15 +164,43 @@ impl UTCDate {
     pub const MIN_YEAR: u64 = 1970;
 
     /// The length of an ISO date (in characters)
+    ///
+    /// Dates with years exceeding `9999` use the expanded ISO representation,
+    /// see [UTCDate::iso_date_len].
     pub const ISO_DATE_LEN: usize = 10;
 
+    /// The maximum length of an ISO date (in characters), in the
+    /// expanded year format `+YYYYYYYYYYYY-MM-DD`
+    pub const MAX_ISO_DATE_LEN: usize = 19;
+
+    /// The maximum year represented without an expanded ISO year
+    const MAX_BASIC_YEAR: u64 = 9999;
+
     /// Unchecked method to create a UTC Date from provided year, month and day.
     ///
     /// ## Safety
     /// Unsafe if the user passes an invalid calendar year, month and day combination.
-    /// Invalid inputs are not checked and may cause a panic in other methods.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_components_unchecked(year: u64, month: u8, day: u8) -> Self {
+        debug_assert!(
+            year >= Self::MIN_YEAR && year <= Self::MAX_YEAR,
+            "unchecked UTCDate year out of range"
+        );
+        debug_assert!(
+            md_to_ordinal(year, month, day).is_some(),
+            "unchecked UTCDate month or day out of range"
+        );
+        Self::_from_components_raw(year, month, day)
+    }
+
+    /// Internal creation of a UTC Date from year, month and day, without any validation.
+    ///
+    /// Allows invalid dates to be held by [UTCDateError::DayOutOfRange].
+    #[inline]
+    const fn _from_components_raw(year: u64, month: u8, day: u8) -> Self {
         let year = year - (month <= 2) as u64;
         let era = year / 400;
         let yoe = year - (era * 400);
@@ -129,16 +212,51 @@ impl UTCDate {
         }
     }
 
-    /// Try to create a UTC Date from provided year, month and day.
-    pub fn try_from_components(year: u64, month: u8, day: u8) -> Result<Self, UTCDateError> {
+    /// Unchecked method to create a UTC Date from its raw internal representation,
+    /// as returned by [UTCDate::as_era_yoe].
+    ///
+    /// The `era` and `yoe` (year of era) are the internal fields of the civil date algorithm,
+    /// not the calendar year. Years begin in March, such that January and February
+    /// belong to the preceding year of era.
+    ///
+    /// ## Safety
+    /// Unsafe if the user passes a representation not obtained from a valid UTC Date.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn from_era_yoe_unchecked(era: u32, yoe: u16, month: u8, day: u8) -> Self {
+        debug_assert!(yoe < 400, "unchecked UTCDate year of era out of range");
+        let date = Self {
+            era,
+            yoe,
+            month,
+            day,
+        };
+        let (year, month, day) = date.as_components();
+        debug_assert!(
+            year >= Self::MIN_YEAR && year <= Self::MAX_YEAR,
+            "unchecked UTCDate year out of range"
+        );
+        debug_assert!(
+            md_to_ordinal(year, month, day).is_some(),
+            "unchecked UTCDate month or day out of range"
+        );
+        date
+    }
+
+    /// Internal validation of year, month and day components,
+    /// shared by the checked component constructors.
+    #[inline]
+    fn _validate_components(year: u64, month: u8, day: u8) -> Result<Self, UTCDateError> {
         if !(Self::MIN_YEAR..=Self::MAX_YEAR).contains(&year) {
             return Err(UTCDateError::YearOutOfRange(year));
         }
         if month == 0 || month > 12 {
             return Err(UTCDateError::MonthOutOfRange(month));
         }
-        // SAFETY: we have checked year and month are within range
-        let date = unsafe { Self::from_components_unchecked(year, month, day) };
+        // year and month are within range, the date may still hold an invalid day
+        let date = Self::_from_components_raw(year, month, day);
         // Then check days
         if date.day == 0 || date.day > date.days_in_month() {
             return Err(UTCDateError::DayOutOfRange(date));
@@ -149,18 +267,128 @@ impl UTCDate {
         Ok(date)
     }
 
+    /// Try to create a UTC Date from provided year, month and day.
+    pub fn try_from_components(year: u64, month: u8, day: u8) -> Result<Self, UTCDateError> {
+        Self::_validate_components(year, month, day)
+    }
+
+    /// Check whether the provided year, month and day form a valid UTC Date.
+    ///
+    /// Returns `true` if and only if [UTCDate::try_from_components] would succeed.
+    #[inline]
+    #[must_use]
+    pub fn is_valid_ymd(year: u64, month: u8, day: u8) -> bool {
+        Self::_validate_components(year, month, day).is_ok()
+    }
+
+    /// Try to create a UTC Date from provided year, and non-zero month and day.
+    ///
+    /// Alternative to [UTCDate::try_from_components] for callers holding
+    /// already validated non-zero components.
+    pub fn from_components_nz(
+        year: u64,
+        month: NonZeroU8,
+        day: NonZeroU8,
+    ) -> Result<Self, UTCDateError> {
+        Self::_validate_components(year, month.get(), day.get())
+    }
+
+    /// Try to create a UTC Date from a year and (1-based) ordinal day of the year.
+    ///
+    /// Inverse of [UTCDate::day_of_year]. Ordinal day `366` is only valid within leap years,
+    /// otherwise [UTCDateError::OrdinalOutOfRange] is raised holding the year's maximum.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::{UTCDate, UTCDateError};
+    ///
+    /// let leap_day = UTCDate::from_ordinal(2024, 60).unwrap();
+    /// assert_eq!(leap_day, UTCDate::try_from_components(2024, 2, 29).unwrap());
+    /// assert!(matches!(
+    ///     UTCDate::from_ordinal(2023, 366),
+    ///     Err(UTCDateError::OrdinalOutOfRange { max: 365, .. })
+    /// ));
+    /// ```
+    pub fn from_ordinal(year: u64, ordinal: u16) -> Result<Self, UTCDateError> {
+        let (month, day) = ordinal_to_md(year, ordinal).ok_or(UTCDateError::OrdinalOutOfRange {
+            year,
+            ordinal,
+            max: _days_before_month(year)[12],
+        })?;
+        Self::try_from_components(year, month, day)
+    }
+
+    /// Try to compute the date of (Western) Easter Sunday of the given year.
+    ///
+    /// Implements the anonymous Gregorian algorithm (Computus).
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::UTCDate;
+    ///
+    /// let easter = UTCDate::easter_sunday(2024).unwrap();
+    /// assert_eq!(easter, UTCDate::try_from_components(2024, 3, 31).unwrap());
+    /// ```
+    pub fn easter_sunday(year: u64) -> Result<Self, UTCDateError> {
+        let a = year % 19;
+        let (b, c) = (year / 100, year % 100);
+        let (d, e) = (b / 4, b % 4);
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b + 15 - d - g) % 30;
+        let (i, k) = (c / 4, c % 4);
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l + 114 - 7 * m;
+        Self::try_from_components(year, (n / 31) as u8, (n % 31 + 1) as u8)
+    }
+
+    /// Try to compute the date of Orthodox Easter Sunday of the given year.
+    ///
+    /// Implements the Julian calendar Computus (Meeus),
+    /// with the resulting date expressed in the (Gregorian) UTC calendar.
+    pub fn orthodox_easter(year: u64) -> Result<Self, UTCDateError> {
+        let (a, b, c) = (year % 4, year % 7, year % 19);
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b + 34 - d) % 7;
+        let n = d + e + 114;
+        // Julian calendar date, always in March or April
+        let julian = Self::try_from_components(year, (n / 31) as u8, (n % 31 + 1) as u8)?;
+        // days the Julian calendar trails the Gregorian calendar, for dates from March 1
+        let offset = year / 100 - year / 400 - 2;
+        julian
+            .checked_add_days(offset)
+            .ok_or(UTCDateError::DateOutOfRange(julian))
+    }
+
     /// Create a UTC Date from the number of days since the epoch.
     ///
+    /// Constant evaluation alternative to `From<UTCDay>`, eg. for building const tables:
+    /// ```rust
+    /// use utc_dt::date::UTCDate;
+    /// use utc_dt::time::{UTCDay, Weekday};
+    ///
+    /// const LIMITS: [(UTCDate, Weekday); 2] = [
+    ///     (UTCDate::from_day(UTCDay::ZERO), UTCDay::ZERO.weekday()),
+    ///     (UTCDate::from_day(UTCDay::MAX), UTCDay::MAX.weekday()),
+    /// ];
+    /// assert_eq!(LIMITS[0], (UTCDate::EPOCH, Weekday::Thursday));
+    /// assert_eq!(LIMITS[1].0, UTCDate::MAX);
+    /// ```
+    ///
     /// Reference:
     /// <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
     ///
     /// Simplified for unsigned days/years
+    #[must_use]
     pub const fn from_day(utc_day: UTCDay) -> Self {
         let z: u64 = utc_day.as_u64() + 719468;
         let era: u32 = (z / 146097) as u32;
         let doe = (z - (era as u64 * 146097)) as u32;
         let yoe = (doe - (doe / 1460) + (doe / 36524) - (doe / 146096)) / 365;
-        let doy = doe - (365 * yoe) - (yoe / 4) + (yoe / 100);
+        let doy = doe + (yoe / 100) - (365 * yoe) - (yoe / 4);
         let mp = ((5 * doy) + 2) / 153;
         let day = (doy - (((153 * mp) + 2) / 5) + 1) as u8;
         let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
@@ -178,6 +406,7 @@ impl UTCDate {
     /// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
     ///
     /// Simplified for unsigned days/years
+    #[must_use]
     pub const fn as_day(&self) -> UTCDay {
         let m = self.month as u16;
         let d = self.day as u16;
@@ -194,25 +423,419 @@ impl UTCDate {
     ///
     /// Returns tuple: `(year: u64, month: u8, day: u8)`
     #[inline]
+    #[must_use]
     pub const fn as_components(&self) -> (u64, u8, u8) {
         let year = self.yoe as u64 + (self.era as u64 * 400) + (self.month <= 2) as u64;
         (year, self.month, self.day)
     }
 
+    /// Get copy of the raw internal date representation
+    ///
+    /// Returns tuple: `(era: u32, yoe: u16, month: u8, day: u8)`
+    ///
+    /// The `era` and `yoe` (year of era) are the internal fields of the civil date algorithm,
+    /// not the calendar year. See [UTCDate::as_components] for the calendar year.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::UTCDate;
+    ///
+    /// // January and February belong to the preceding year of era
+    /// let date = UTCDate::try_from_components(2000, 1, 1).unwrap();
+    /// assert_eq!(date.as_era_yoe(), (4, 399, 1, 1));
+    /// let date = UTCDate::try_from_components(2000, 3, 1).unwrap();
+    /// assert_eq!(date.as_era_yoe(), (5, 0, 3, 1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_era_yoe(&self) -> (u32, u16, u8, u8) {
+        (self.era, self.yoe, self.month, self.day)
+    }
+
+    /// Get the date as named year, month and day components
+    #[must_use]
+    pub const fn components(&self) -> DateComponents {
+        let (year, month, day) = self.as_components();
+        DateComponents { year, month, day }
+    }
+
+    /// Try to create a UTC Date from named components.
+    ///
+    /// Components are validated as per [UTCDate::try_from_components].
+    pub fn try_from_date_components(components: DateComponents) -> Result<Self, UTCDateError> {
+        Self::try_from_components(components.year, components.month, components.day)
+    }
+
     /// Consume self into date components as integers
     ///
     /// Returns tuple: `(year: u64, month: u8, day: u8)`
     #[inline]
+    #[must_use]
     pub const fn to_components(self) -> (u64, u8, u8) {
         let year = self.yoe as u64 + (self.era as u64 * 400) + (self.month <= 2) as u64;
         (year, self.month, self.day)
     }
 
+    /// Get the equivalent date in the (proleptic) Julian calendar
+    ///
+    /// Returns tuple: `(year: u64, month: u8, day: u8)`
+    ///
+    /// UTC Dates are in the proleptic Gregorian calendar, and begin at the Unix Epoch (1970-01-01),
+    /// long after the Gregorian reform of 1582. Conversion is therefore unambiguous,
+    /// without year zero or calendar switchover handling; the epoch maps to Julian `1969-12-19`.
+    /// Dates preceding the epoch (only constructible via unchecked methods) are unsupported,
+    /// and panic in debug builds.
+    ///
+    /// Reference:
+    /// <https://en.wikipedia.org/wiki/Julian_day#Julian_or_Gregorian_calendar_from_Julian_day_number>
+    ///
+    /// Simplified for unsigned days/years
+    #[must_use]
+    pub const fn to_julian_calendar(&self) -> (u64, u8, u8) {
+        debug_assert!(
+            self.as_components().0 >= Self::MIN_YEAR,
+            "UTCDate precedes the epoch, Julian conversion is unsupported"
+        );
+        // Julian day number of the UTC day, offset to March 1st, 4801 BC (Julian)
+        let c = self.as_day().as_u64() + 2440588 + 32082;
+        let d = ((4 * c) + 3) / 1461;
+        let e = c - ((1461 * d) / 4);
+        let m = ((5 * e) + 2) / 153;
+        let day = (e - (((153 * m) + 2) / 5) + 1) as u8;
+        let month = (m + 3 - (12 * (m / 10))) as u8;
+        let year = d + (m / 10) - 4800;
+        (year, month, day)
+    }
+
+    /// Get a stable 64-bit FNV-1a hash of the date.
+    ///
+    /// Equal to the hash of the date's [UTCDay], see [UTCDay::fnv1a_hash].
+    #[inline]
+    #[must_use]
+    pub const fn fnv1a_hash(&self) -> u64 {
+        self.as_day().fnv1a_hash()
+    }
+
+    /// Get the (1-based) ordinal day of the year of the date, in the range `[1, 366]`
+    #[must_use]
+    pub fn day_of_year(&self) -> u16 {
+        let (year, month, day) = self.as_components();
+        // date components are always valid
+        md_to_ordinal(year, month, day).unwrap_or_default()
+    }
+
+    /// Get the first date of the year of the date (January 1)
+    #[must_use]
+    pub const fn start_of_year(&self) -> Self {
+        let (year, _, _) = self.as_components();
+        // SAFETY: the first day of the year of a valid date is valid
+        unsafe { Self::from_components_unchecked(year, 1, 1) }
+    }
+
+    /// Get the last date of the year of the date (December 31)
+    ///
+    /// Saturates at [UTCDate::MAX].
+    #[must_use]
+    pub fn end_of_year(&self) -> Self {
+        let (year, _, _) = self.as_components();
+        // SAFETY: the last day of the year is valid, other than exceeding the maximum date
+        unsafe { Self::from_components_unchecked(year, 12, 31) }.min(Self::MAX)
+    }
+
+    /// Calculate and return the day of the week of the date
+    #[inline]
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.as_day().weekday()
+    }
+
+    /// Get the fiscal year of the date, for a fiscal year starting on the first day
+    /// of `fy_start_month` (`[1, 12]`).
+    ///
+    /// Fiscal years are labelled by the calendar year in which they start.
+    /// eg. with an April start, `2023-03-15` falls within fiscal year `2022`,
+    /// and `2023-04-01` begins fiscal year `2023`.
+    /// A January start matches the calendar year.
+    ///
+    /// Returns [`None`] if `fy_start_month` is not a valid month.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::UTCDate;
+    ///
+    /// let date = UTCDate::try_from_components(2023, 3, 15).unwrap();
+    /// assert_eq!(date.fiscal_year(4), Some(2022));
+    /// assert_eq!(date.fiscal_quarter(4), Some(4));
+    /// ```
+    #[must_use]
+    pub const fn fiscal_year(&self, fy_start_month: u8) -> Option<u64> {
+        if fy_start_month == 0 || fy_start_month > 12 {
+            return None;
+        }
+        let (year, month, _) = self.as_components();
+        if month >= fy_start_month {
+            Some(year)
+        } else {
+            Some(year - 1)
+        }
+    }
+
+    /// Get the fiscal quarter (`[1, 4]`) of the date, for a fiscal year starting on the first day
+    /// of `fy_start_month` (`[1, 12]`).
+    ///
+    /// Each quarter spans three calendar months, the first starting with `fy_start_month`.
+    /// See [UTCDate::fiscal_year] for the fiscal year labelling convention.
+    ///
+    /// Returns [`None`] if `fy_start_month` is not a valid month.
+    #[must_use]
+    pub const fn fiscal_quarter(&self, fy_start_month: u8) -> Option<u8> {
+        if fy_start_month == 0 || fy_start_month > 12 {
+            return None;
+        }
+        let (_, month, _) = self.as_components();
+        let fiscal_month_idx = (month + 12 - fy_start_month) % 12;
+        Some(fiscal_month_idx / 3 + 1)
+    }
+
+    /// Check whether both dates fall within the same calendar year
+    #[inline]
+    #[must_use]
+    pub const fn is_same_year(&self, other: &Self) -> bool {
+        self.as_components().0 == other.as_components().0
+    }
+
+    /// Check whether both dates fall within the same calendar month (of the same year)
+    #[inline]
+    #[must_use]
+    pub const fn is_same_month(&self, other: &Self) -> bool {
+        self.month == other.month && self.is_same_year(other)
+    }
+
+    /// Checked addition of days to the date, returning [`None`]
+    /// if the resulting date would exceed [UTCDate::MAX].
+    #[must_use]
+    pub fn checked_add_days(&self, days: u64) -> Option<Self> {
+        let day = self.as_day().as_u64().checked_add(days)?;
+        let utc_day = UTCDay::try_from_u64(day).ok()?;
+        Some(Self::from_day(utc_day))
+    }
+
+    /// Checked subtraction of days from the date, returning [`None`]
+    /// if the resulting date would precede [UTCDate::MIN].
+    #[must_use]
+    pub fn checked_sub_days(&self, days: u64) -> Option<Self> {
+        let day = self.as_day().as_u64().checked_sub(days)?;
+        // SAFETY: day does not exceed the day of this date
+        Some(Self::from_day(unsafe { UTCDay::from_u64_unchecked(day) }))
+    }
+
+    /// Internal creation of a date from months since year 0 (January as month 0),
+    /// clamping the day to the last day of the resulting month.
+    fn _from_months_clamped(months: u64, day: u8) -> Option<Self> {
+        let year = months / 12;
+        let month = (months % 12) as u8 + 1;
+        if !(Self::MIN_YEAR..=Self::MAX_YEAR).contains(&year) {
+            return None;
+        }
+        // SAFETY: year and month are within range, and the first day exists in every month
+        let first = unsafe { Self::from_components_unchecked(year, month, 1) };
+        let date = Self {
+            day: day.min(first.days_in_month()),
+            ..first
+        };
+        (date <= Self::MAX).then_some(date)
+    }
+
+    /// Internal count of months since year 0 (January as month 0)
+    const fn _as_months(&self) -> u64 {
+        let (year, month, _) = self.as_components();
+        (year * 12) + (month as u64 - 1)
+    }
+
+    /// Checked addition of calendar months to the date, returning [`None`]
+    /// if the resulting date would exceed [UTCDate::MAX].
+    ///
+    /// Where the day does not exist in the resulting month,
+    /// it is clamped to the last day of the month (eg. Jan 31 + 1 month = Feb 28).
+    #[must_use]
+    pub fn checked_add_months(&self, months: u64) -> Option<Self> {
+        Self::_from_months_clamped(self._as_months().checked_add(months)?, self.day)
+    }
+
+    /// Checked subtraction of calendar months from the date, returning [`None`]
+    /// if the resulting date would precede [UTCDate::MIN].
+    ///
+    /// Where the day does not exist in the resulting month,
+    /// it is clamped to the last day of the month (eg. Mar 31 - 1 month = Feb 28).
+    #[must_use]
+    pub fn checked_sub_months(&self, months: u64) -> Option<Self> {
+        Self::_from_months_clamped(self._as_months().checked_sub(months)?, self.day)
+    }
+
+    /// Get the same day of the next month, returning [`None`]
+    /// if the resulting date would exceed [UTCDate::MAX].
+    ///
+    /// Where the day does not exist in the next month,
+    /// it is clamped to the last day of the month (eg. Jan 31 to Feb 28).
+    #[inline]
+    #[must_use]
+    pub fn checked_next_month(&self) -> Option<Self> {
+        self.checked_add_months(1)
+    }
+
+    /// Get the same day of the previous month, returning [`None`]
+    /// if the resulting date would precede [UTCDate::MIN].
+    ///
+    /// Where the day does not exist in the previous month,
+    /// it is clamped to the last day of the month (eg. Mar 31 to Feb 28).
+    #[inline]
+    #[must_use]
+    pub fn checked_prev_month(&self) -> Option<Self> {
+        self.checked_sub_months(1)
+    }
+
+    /// Count the whole calendar months elapsed from this date until `as_of`.
+    ///
+    /// A month is complete once the day of the month is reached, with days exceeding
+    /// the length of a month clamped to its last day, consistent with [UTCDate::checked_add_months].
+    /// That is, the largest `n` where `self + Months(n) <= as_of`
+    /// (eg. from Jan 31 to Feb 28 of a common year is 1 month).
+    ///
+    /// Returns `0` if `as_of` precedes this date.
+    #[must_use]
+    pub fn completed_months_since(self, as_of: UTCDate) -> u64 {
+        if as_of <= self {
+            return 0;
+        }
+        let months = as_of._as_months() - self._as_months();
+        let day = self.day.min(as_of.days_in_month());
+        if as_of.day < day {
+            months - 1
+        } else {
+            months
+        }
+    }
+
+    /// Count the whole calendar years elapsed from this date until `as_of`.
+    ///
+    /// February 29 is clamped to February 28 in non-leap years, consistent with [UTCDate::checked_add_years].
+    /// That is, the largest `n` where `self + Years(n) <= as_of`.
+    ///
+    /// Returns `0` if `as_of` precedes this date.
+    #[must_use]
+    pub fn completed_years_since(self, as_of: UTCDate) -> u64 {
+        self.completed_months_since(as_of) / 12
+    }
+
+    /// Get the signed number of days from `other` to this date.
+    ///
+    /// Negative if this date precedes `other`.
+    #[inline]
+    #[must_use]
+    pub const fn signed_days_since(self, other: UTCDate) -> i64 {
+        // days since the epoch do not exceed i64::MAX
+        self.as_day().as_u64() as i64 - other.as_day().as_u64() as i64
+    }
+
+    /// Get the whole calendar years and remaining days from `other` to this date,
+    /// eg. for human-readable differences ("2 years, 40 days").
+    ///
+    /// Years are counted as per [UTCDate::completed_years_since],
+    /// with the remaining days counted from the anniversary of the earlier date.
+    /// The years are negative if this date precedes `other`, while the days are always
+    /// the magnitude of the remainder. The direction of spans shorter than a year
+    /// is given by [UTCDate::signed_days_since].
+    #[must_use]
+    pub fn years_and_days_since(self, other: UTCDate) -> (i64, u16) {
+        let (earlier, later) = if self < other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let years = earlier.completed_years_since(later);
+        // anniversary does not exceed the later date
+        let anniversary = earlier + Years(years);
+        let days = later.signed_days_since(anniversary) as u16;
+        if self < other {
+            (-(years as i64), days)
+        } else {
+            (years as i64, days)
+        }
+    }
+
+    /// Checked addition of calendar years to the date, returning [`None`]
+    /// if the resulting date would exceed [UTCDate::MAX].
+    ///
+    /// February 29 is clamped to February 28 in non-leap years.
+    #[must_use]
+    pub fn checked_add_years(&self, years: u64) -> Option<Self> {
+        self.checked_add_months(years.checked_mul(12)?)
+    }
+
+    /// Checked subtraction of calendar years from the date, returning [`None`]
+    /// if the resulting date would precede [UTCDate::MIN].
+    ///
+    /// February 29 is clamped to February 28 in non-leap years.
+    #[must_use]
+    pub fn checked_sub_years(&self, years: u64) -> Option<Self> {
+        self.checked_sub_months(years.checked_mul(12)?)
+    }
+
+    /// Get the first date on or after this date falling on the given weekday.
+    ///
+    /// Returns [`None`] if such a date would exceed [UTCDate::MAX].
+    #[must_use]
+    pub fn next_weekday_inclusive(&self, weekday: Weekday) -> Option<Self> {
+        self.checked_add_days(self.weekday().days_until(weekday) as u64)
+    }
+
+    /// Iterate all dates falling on the given weekday between `start` and `end` (inclusive).
+    ///
+    /// Yields nothing if `start` exceeds `end`.
+    pub fn iter_weekday_between(
+        start: UTCDate,
+        end: UTCDate,
+        weekday: Weekday,
+    ) -> impl Iterator<Item = UTCDate> {
+        core::iter::successors(start.next_weekday_inclusive(weekday), |date| {
+            date.checked_add_days(7)
+        })
+        .take_while(move |date| *date <= end)
+    }
+
+    /// Count the occurrences of a weekday within a calendar month, either `4` or `5`.
+    ///
+    /// Returns an error if the year or month are out of range.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::UTCDate;
+    /// use utc_dt::time::Weekday;
+    ///
+    /// // January 2024 begins on a Monday
+    /// assert_eq!(UTCDate::count_weekday_in_month(2024, 1, Weekday::Monday).unwrap(), 5);
+    /// assert_eq!(UTCDate::count_weekday_in_month(2024, 1, Weekday::Sunday).unwrap(), 4);
+    /// ```
+    pub fn count_weekday_in_month(
+        year: u64,
+        month: u8,
+        weekday: Weekday,
+    ) -> Result<u8, UTCDateError> {
+        let first = Self::try_from_components(year, month, 1)?;
+        let offset = first.weekday().days_until(weekday);
+        // the first occurrence always falls within the first week of the month
+        Ok((first.days_in_month() - 1 - offset) / 7 + 1)
+    }
+
     /// Returns whether date is within a leap year.
     ///
     /// Reference:
     /// <http://howardhinnant.github.io/date_algorithms.html#is_leap>
     #[inline]
+    #[must_use]
     pub const fn is_leap_year(&self) -> bool {
         let yoe_adj = self.yoe + (self.month <= 2) as u16;
         (yoe_adj % 4 == 0) && ((yoe_adj % 100 != 0) || (yoe_adj % 400 == 0))
@@ -220,51 +843,158 @@ impl UTCDate {
 
     /// Returns the number of days within the month of the date.
     /// Leap years are accounted for.
+    #[must_use]
     pub fn days_in_month(&self) -> u8 {
-        match self.month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            _ => {
-                if self.is_leap_year() {
-                    29
-                } else {
-                    28
-                }
-            }
-        }
+        let days_before_month = if self.is_leap_year() {
+            &DAYS_BEFORE_MONTH_LEAP
+        } else {
+            &DAYS_BEFORE_MONTH
+        };
+        let month = self.month as usize;
+        (days_before_month[month] - days_before_month[month - 1]) as u8
     }
 
     /// Try parse date from str in the format:
-    /// * `YYYY-MM-DD`
+    /// * `YYYY-MM-DD` or
+    /// * `+YYYYY-MM-DD` (expanded year of 5 or more digits, without leading zeros)
     ///
     /// Conforms to ISO 8601:
     /// <https://www.w3.org/TR/NOTE-datetime>
     pub fn try_from_iso_date(iso: &str) -> Result<Self, UTCDateError> {
-        let len = iso.len();
-        if len != Self::ISO_DATE_LEN {
-            return Err(UTCDateError::InvalidStrLen(len));
+        // valid ISO dates are ASCII, which allows byte slicing without panics
+        if !iso.is_ascii() {
+            return Err(UTCDateError::InvalidFormat);
         }
+        let len = iso.len();
         // handle slice
-        let (year_str, rem) = iso.split_at(4); // remainder = "-MM-DD"
+        let (year_str, rem) = match iso.strip_prefix('+') {
+            Some(expanded) => {
+                if !(Self::ISO_DATE_LEN + 2..=Self::MAX_ISO_DATE_LEN).contains(&len) {
+                    return Err(UTCDateError::InvalidStrLen(len));
+                }
+                // expanded years are canonical, rejecting signs and leading zeros
+                let (year_str, rem) = expanded.split_at(len - 7); // remainder = "-MM-DD"
+                if year_str.starts_with('0') || !year_str.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(UTCDateError::InvalidFormat);
+                }
+                (year_str, rem)
+            }
+            None => {
+                if len != Self::ISO_DATE_LEN {
+                    return Err(UTCDateError::InvalidStrLen(len));
+                }
+                iso.split_at(4) // remainder = "-MM-DD"
+            }
+        };
         let (month_str, rem) = rem[1..].split_at(2); // remainder = "-DD"
         let day_str = &rem[1..];
         // parse
-        let year: u64 = year_str.parse()?;
+        let year = Self::_parse_year(year_str)?;
         let month: u8 = month_str.parse()?;
         let day: u8 = day_str.parse()?;
         Self::try_from_components(year, month, day)
     }
 
+    /// Try parse date from str as per [UTCDate::try_from_iso_date],
+    /// ignoring leading and trailing ASCII whitespace (eg. stray spaces in CSV fields).
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::UTCDate;
+    ///
+    /// let date = UTCDate::try_from_iso_date_trimmed(" 2023-06-15\t").unwrap();
+    /// assert_eq!(date, UTCDate::try_from_iso_date("2023-06-15").unwrap());
+    /// ```
+    pub fn try_from_iso_date_trimmed(iso: &str) -> Result<Self, UTCDateError> {
+        Self::try_from_iso_date(iso.trim_matches(|c: char| c.is_ascii_whitespace()))
+    }
+
+    /// Try parse date from an English str, in one of the formats:
+    /// * `15 June 2023` (day, month, year)
+    /// * `June 15 2023` (month, day, year)
+    ///
+    /// Months may be full or abbreviated (three letter) English names, ignoring ASCII case,
+    /// see [Month::from_english]. Components are separated by ASCII whitespace,
+    /// optionally following a comma (eg. `Jun 15, 2023`).
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::date::UTCDate;
+    ///
+    /// let date = UTCDate::try_from_components(2023, 6, 15).unwrap();
+    /// assert_eq!(UTCDate::try_from_english("15 June 2023").unwrap(), date);
+    /// assert_eq!(UTCDate::try_from_english("Jun 15, 2023").unwrap(), date);
+    /// ```
+    pub fn try_from_english(s: &str) -> Result<Self, UTCDateError> {
+        let mut tokens = s
+            .split_ascii_whitespace()
+            .map(|token| token.strip_suffix(',').unwrap_or(token));
+        let (Some(first), Some(second), Some(year_str), None) =
+            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+        else {
+            return Err(UTCDateError::InvalidFormat);
+        };
+        let (month, day_str) = match (Month::from_english(first), Month::from_english(second)) {
+            (None, Some(month)) => (month, first),
+            (Some(month), None) => (month, second),
+            _ => return Err(UTCDateError::InvalidFormat),
+        };
+        let is_numeric = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if !is_numeric(day_str) || !is_numeric(year_str) || day_str.len() > 2 {
+            return Err(UTCDateError::InvalidFormat);
+        }
+        let day: u8 = day_str.parse()?;
+        let year = Self::_parse_year(year_str)?;
+        Self::try_from_components(year, month.as_u8(), day)
+    }
+
+    /// Internal parse of a decimal year str,
+    /// mapping `u64` overflow to [UTCDateError::YearOutOfRange] rather than a parse error
+    pub(crate) fn _parse_year(year_str: &str) -> Result<u64, UTCDateError> {
+        year_str.parse().map_err(|e: ParseIntError| match e.kind() {
+            IntErrorKind::PosOverflow => UTCDateError::YearOutOfRange(u64::MAX),
+            _ => UTCDateError::ParseErr(e),
+        })
+    }
+
     /// Return date as a string in the format:
-    /// * `YYYY-MM-DD`
+    /// * `YYYY-MM-DD` or
+    /// * `+YYYYY-MM-DD` (years exceeding `9999`)
     ///
     /// Conforms to ISO 8601:
     /// <https://www.w3.org/TR/NOTE-datetime>
     #[cfg(feature = "alloc")]
+    #[must_use]
     pub fn as_iso_date(&self) -> String {
         format!("{self}")
     }
 
+    /// Return date as a string with the common era suffix, in the format:
+    /// * `YYYY-MM-DD CE`
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn common_era_string(&self) -> String {
+        format!("{self} CE")
+    }
+
+    /// Calculate the number of characters in the ISO representation of this date
+    ///
+    /// Equal to [UTCDate::ISO_DATE_LEN] (10) unless the year exceeds `9999`,
+    /// requiring the expanded year format.
+    #[inline]
+    #[must_use]
+    pub const fn iso_date_len(&self) -> usize {
+        let (year, _, _) = self.as_components();
+        if year > Self::MAX_BASIC_YEAR {
+            // sign + year digits + "-MM-DD"
+            1 + year.ilog10() as usize + 1 + 6
+        } else {
+            Self::ISO_DATE_LEN
+        }
+    }
+
     /// Internal truncated buffer write
     #[inline]
     pub(crate) fn _write_iso_date_trunc(&self, w: &mut StrWriter) {
@@ -273,9 +1003,11 @@ impl UTCDate {
     }
 
     /// Write an ISO date to a buffer in the format:
-    /// * `YYYY-MM-DD`
+    /// * `YYYY-MM-DD` or
+    /// * `+YYYYY-MM-DD` (years exceeding `9999`)
     ///
-    /// The buffer should have minimum length of [UTCDate::ISO_DATE_LEN] (10).
+    /// The buffer should have minimum length of [UTCDate::iso_date_len],
+    /// or [UTCDate::MAX_ISO_DATE_LEN] (19) to fit any date.
     ///
     /// A buffer of insufficient length will error ([UTCDateError::InvalidStrLen]).
     ///
@@ -284,7 +1016,7 @@ impl UTCDate {
     /// Conforms to ISO 8601:
     /// <https://www.w3.org/TR/NOTE-datetime>
     pub fn write_iso_date(&self, buf: &mut [u8]) -> Result<usize, UTCDateError> {
-        let write_len = Self::ISO_DATE_LEN;
+        let write_len = self.iso_date_len();
         if write_len > buf.len() {
             return Err(UTCDateError::InvalidStrLen(buf.len()));
         }
@@ -292,6 +1024,19 @@ impl UTCDate {
         self._write_iso_date_trunc(&mut writer);
         Ok(writer.written)
     }
+
+    /// Return date as a stack allocated ISO str in the format:
+    /// * `YYYY-MM-DD` or
+    /// * `+YYYYY-MM-DD` (years exceeding `9999`)
+    ///
+    /// Capacity is [UTCDate::MAX_ISO_DATE_LEN] (19), to fit dates with expanded years.
+    ///
+    /// Conforms to ISO 8601:
+    /// <https://www.w3.org/TR/NOTE-datetime>
+    #[must_use]
+    pub fn to_iso_array(&self) -> IsoStr<{ Self::MAX_ISO_DATE_LEN }> {
+        IsoStr::_from_trunc_write(self.iso_date_len(), |w| self._write_iso_date_trunc(w))
+    }
 }
 
 impl UTCTransformations for UTCDate {
@@ -341,6 +1086,18 @@ impl UTCTransformations for UTCDate {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for UTCDate {
+    /// Generates dates within `[UTCDate::MIN, UTCDate::MAX]`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_day(UTCDay::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        UTCDay::size_hint(depth)
+    }
+}
+
 impl From<Duration> for UTCDate {
     fn from(duration: Duration) -> Self {
         Self::from_duration(duration)
@@ -354,26 +1111,382 @@ impl From<UTCTimestamp> for UTCDate {
 }
 
 impl From<UTCDay> for UTCDate {
+    /// See [UTCDate::from_day] for constant evaluation.
     fn from(utc_day: UTCDay) -> Self {
         Self::from_day(utc_day)
     }
 }
 
+/// Internal selection of the cumulative days-before-month table for a year
+const fn _days_before_month(year: u64) -> &'static [u16; 13] {
+    let is_leap_year = (year % 4 == 0) && ((year % 100 != 0) || (year % 400 == 0));
+    if is_leap_year {
+        &DAYS_BEFORE_MONTH_LEAP
+    } else {
+        &DAYS_BEFORE_MONTH
+    }
+}
+
+/// Convert a (1-based) ordinal day of the year to the month and day of the month
+/// within the proleptic Gregorian calendar year.
+///
+/// Returns [`None`] if the ordinal is zero or exceeds the number of days in the year.
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::date::ordinal_to_md;
+///
+/// assert_eq!(ordinal_to_md(2023, 60), Some((3, 1)));
+/// assert_eq!(ordinal_to_md(2024, 60), Some((2, 29)));
+/// ```
+#[must_use]
+pub const fn ordinal_to_md(year: u64, ordinal: u16) -> Option<(u8, u8)> {
+    let days_before_month = _days_before_month(year);
+    if ordinal == 0 || ordinal > days_before_month[12] {
+        return None;
+    }
+    let mut month = 1;
+    while ordinal > days_before_month[month] {
+        month += 1;
+    }
+    Some((month as u8, (ordinal - days_before_month[month - 1]) as u8))
+}
+
+/// Convert a month and day of the month to the (1-based) ordinal day of the year
+/// within the proleptic Gregorian calendar year.
+///
+/// Returns [`None`] if the month or day is out of range for the year.
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::date::md_to_ordinal;
+///
+/// assert_eq!(md_to_ordinal(2023, 3, 1), Some(60));
+/// assert_eq!(md_to_ordinal(2024, 2, 29), Some(60));
+/// assert_eq!(md_to_ordinal(2023, 2, 29), None);
+/// ```
+#[must_use]
+pub const fn md_to_ordinal(year: u64, month: u8, day: u8) -> Option<u16> {
+    if month == 0 || month > 12 || day == 0 {
+        return None;
+    }
+    let days_before_month = _days_before_month(year);
+    let month = month as usize;
+    let ordinal = days_before_month[month - 1] + day as u16;
+    if ordinal > days_before_month[month] {
+        return None;
+    }
+    Some(ordinal)
+}
+
+/// A number of days, for type-safe [UTCDate] and [UTCDatetime](crate::UTCDatetime) arithmetic.
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::date::{Days, Months, Years, UTCDate};
+///
+/// let date = UTCDate::try_from_components(2024, 1, 31).unwrap();
+/// assert_eq!(date + Days(30), UTCDate::try_from_components(2024, 3, 1).unwrap());
+/// assert_eq!(date + Months(1), UTCDate::try_from_components(2024, 2, 29).unwrap());
+/// assert_eq!(date - Years(1), UTCDate::try_from_components(2023, 1, 31).unwrap());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Days(pub u64);
+
+/// A number of calendar months, for type-safe [UTCDate] and [UTCDatetime](crate::UTCDatetime) arithmetic.
+///
+/// See [UTCDate::checked_add_months] for handling of days exceeding the resulting month.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Months(pub u64);
+
+/// A number of calendar years, for type-safe [UTCDate] and [UTCDatetime](crate::UTCDatetime) arithmetic.
+///
+/// See [UTCDate::checked_add_years] for handling of leap days.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Years(pub u64);
+
+impl Add<Days> for UTCDate {
+    type Output = UTCDate;
+
+    fn add(self, rhs: Days) -> Self::Output {
+        self.checked_add_days(rhs.0)
+            .expect("overflow when adding days to date")
+    }
+}
+
+impl AddAssign<Days> for UTCDate {
+    fn add_assign(&mut self, rhs: Days) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Days> for UTCDate {
+    type Output = UTCDate;
+
+    fn sub(self, rhs: Days) -> Self::Output {
+        self.checked_sub_days(rhs.0)
+            .expect("overflow when subtracting days from date")
+    }
+}
+
+impl SubAssign<Days> for UTCDate {
+    fn sub_assign(&mut self, rhs: Days) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<Months> for UTCDate {
+    type Output = UTCDate;
+
+    fn add(self, rhs: Months) -> Self::Output {
+        self.checked_add_months(rhs.0)
+            .expect("overflow when adding months to date")
+    }
+}
+
+impl AddAssign<Months> for UTCDate {
+    fn add_assign(&mut self, rhs: Months) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Months> for UTCDate {
+    type Output = UTCDate;
+
+    fn sub(self, rhs: Months) -> Self::Output {
+        self.checked_sub_months(rhs.0)
+            .expect("overflow when subtracting months from date")
+    }
+}
+
+impl SubAssign<Months> for UTCDate {
+    fn sub_assign(&mut self, rhs: Months) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<Years> for UTCDate {
+    type Output = UTCDate;
+
+    fn add(self, rhs: Years) -> Self::Output {
+        self.checked_add_years(rhs.0)
+            .expect("overflow when adding years to date")
+    }
+}
+
+impl AddAssign<Years> for UTCDate {
+    fn add_assign(&mut self, rhs: Years) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Years> for UTCDate {
+    type Output = UTCDate;
+
+    fn sub(self, rhs: Years) -> Self::Output {
+        self.checked_sub_years(rhs.0)
+            .expect("overflow when subtracting years from date")
+    }
+}
+
+impl SubAssign<Years> for UTCDate {
+    fn sub_assign(&mut self, rhs: Years) {
+        *self = *self - rhs;
+    }
+}
+
+/// Named components of a date
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateComponents {
+    /// Year, from `1970`
+    pub year: u64,
+    /// Month, `[1, 12]`
+    pub month: u8,
+    /// Day of the month, `[1, 31]`
+    pub day: u8,
+}
+
+impl From<UTCDate> for DateComponents {
+    fn from(date: UTCDate) -> Self {
+        date.components()
+    }
+}
+
+impl TryFrom<DateComponents> for UTCDate {
+    type Error = UTCDateError;
+
+    fn try_from(components: DateComponents) -> Result<Self, Self::Error> {
+        Self::try_from_date_components(components)
+    }
+}
+
+/// Component of a date
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateComponent {
+    /// Year component (`YYYY`)
+    Year,
+    /// Month component (`MM`)
+    Month,
+    /// Day component (`DD`)
+    Day,
+}
+
+impl Display for DateComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Year => write!(f, "year"),
+            Self::Month => write!(f, "month"),
+            Self::Day => write!(f, "day"),
+        }
+    }
+}
+
+/// Month of the year.
+///
+/// Numbered `[1, 12]` from January to December, consistent with [UTCDate::as_components].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Month {
+    /// January (1)
+    January = 1,
+    /// February (2)
+    February = 2,
+    /// March (3)
+    March = 3,
+    /// April (4)
+    April = 4,
+    /// May (5)
+    May = 5,
+    /// June (6)
+    June = 6,
+    /// July (7)
+    July = 7,
+    /// August (8)
+    August = 8,
+    /// September (9)
+    September = 9,
+    /// October (10)
+    October = 10,
+    /// November (11)
+    November = 11,
+    /// December (12)
+    December = 12,
+}
+
+impl Month {
+    /// All months, in numerical order from January
+    pub const ALL: [Self; 12] = [
+        Self::January,
+        Self::February,
+        Self::March,
+        Self::April,
+        Self::May,
+        Self::June,
+        Self::July,
+        Self::August,
+        Self::September,
+        Self::October,
+        Self::November,
+        Self::December,
+    ];
+
+    /// Month in numerical form, `[1, 12]` represents `[Jan, Dec]`
+    #[inline]
+    #[must_use]
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Try to create a month from its numerical form, `[1, 12]` represents `[Jan, Dec]`
+    pub const fn try_from_u8(month: u8) -> Result<Self, UTCDateError> {
+        match month {
+            1..=12 => Ok(Self::ALL[month as usize - 1]),
+            _ => Err(UTCDateError::MonthOutOfRange(month)),
+        }
+    }
+
+    /// Full English name of the month (eg. `June`)
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::January => "January",
+            Self::February => "February",
+            Self::March => "March",
+            Self::April => "April",
+            Self::May => "May",
+            Self::June => "June",
+            Self::July => "July",
+            Self::August => "August",
+            Self::September => "September",
+            Self::October => "October",
+            Self::November => "November",
+            Self::December => "December",
+        }
+    }
+
+    /// Abbreviated (three letter) English name of the month (eg. `Jun`)
+    #[must_use]
+    pub fn abbreviation(&self) -> &'static str {
+        &self.name()[..3]
+    }
+
+    /// Get the month from its full or abbreviated English name, ignoring ASCII case.
+    ///
+    /// Returns [`None`] if the name is not recognized.
+    #[must_use]
+    pub fn from_english(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|month| {
+            month.name().eq_ignore_ascii_case(name)
+                || month.abbreviation().eq_ignore_ascii_case(name)
+        })
+    }
+}
+
+impl Display for Month {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 /// Error type for UTCDate methods
 #[derive(Debug, Clone)]
 pub enum UTCDateError {
     /// Error raised parsing int to string
     ParseErr(ParseIntError),
-    /// Error raised due to out of range year
+    /// Error raised due to out of range year.
+    ///
+    /// Holds `u64::MAX` for parsed years overflowing a `u64`.
     YearOutOfRange(u64),
     /// Error raised due to out of range month
     MonthOutOfRange(u8),
     /// Error raised due to out of range day
+    ///
+    /// Holds the invalid date, from which the valid maximum
+    /// ([UTCDate::days_in_month]) is reported on display.
     DayOutOfRange(UTCDate),
     /// Error raised due to out of range date
     DateOutOfRange(UTCDate),
     /// Error raised due to invalid ISO date length
     InvalidStrLen(usize),
+    /// Error raised due to out of range ordinal day of the year
+    OrdinalOutOfRange {
+        /// The year of the ordinal day
+        year: u64,
+        /// The out of range ordinal day
+        ordinal: u16,
+        /// The number of days in the year, `365` or `366`
+        max: u16,
+    },
+    /// Error raised due to input str not matching a supported format
+    InvalidFormat,
 }
 
 impl Display for UTCDateError {
@@ -382,9 +1495,25 @@ impl Display for UTCDateError {
             Self::ParseErr(e) => e.fmt(f),
             Self::YearOutOfRange(y) => write!(f, "year ({y}) out of range!"),
             Self::MonthOutOfRange(m) => write!(f, "month ({m}) out of range!"),
-            Self::DayOutOfRange(d) => write!(f, "day ({d}) out of range!"),
+            Self::DayOutOfRange(d) => {
+                let (year, month, day) = d.as_components();
+                // the month is validated before the day, so is always within range
+                let month = Month::ALL[month as usize - 1];
+                let max = d.days_in_month();
+                write!(f, "day {day} out of range for {month} {year} (max {max})")
+            }
             Self::DateOutOfRange(date) => write!(f, "date ({date}) out of range!"),
-            Self::InvalidStrLen(l) => write!(f, "invalid ISO date str length ({l}), 10 required"),
+            Self::InvalidStrLen(l) => write!(
+                f,
+                "invalid ISO date str length ({l}), 10 required (12 to 19 for expanded years)"
+            ),
+            Self::OrdinalOutOfRange { year, ordinal, max } => {
+                write!(
+                    f,
+                    "ordinal day {ordinal} out of range for {year} (max {max})"
+                )
+            }
+            Self::InvalidFormat => write!(f, "date str does not match a supported format"),
         }
     }
 }
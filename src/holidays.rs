@@ -0,0 +1,33 @@
+//! Holidays module.
+//!
+//! Implements constructors for common fixed-date observances of a given year.
+//!
+//! Movable observances (eg. Easter) are not fixed to a calendar date and are not provided here.
+
+use crate::date::{UTCDate, UTCDateError};
+
+/// New Year's Day, January 1 of the given year.
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::date::UTCDate;
+/// use utc_dt::holidays::new_years_day;
+///
+/// assert_eq!(new_years_day(2024).unwrap(), UTCDate::try_from_components(2024, 1, 1).unwrap());
+/// // years preceding the Unix Epoch are not supported
+/// assert!(new_years_day(1969).is_err());
+/// ```
+pub fn new_years_day(year: u64) -> Result<UTCDate, UTCDateError> {
+    UTCDate::try_from_components(year, 1, 1)
+}
+
+/// Independence Day (United States), July 4 of the given year.
+pub fn us_independence_day(year: u64) -> Result<UTCDate, UTCDateError> {
+    UTCDate::try_from_components(year, 7, 4)
+}
+
+/// Christmas Day, December 25 of the given year.
+pub fn christmas(year: u64) -> Result<UTCDate, UTCDateError> {
+    UTCDate::try_from_components(year, 12, 25)
+}
@@ -0,0 +1,174 @@
+//! Interval module.
+//!
+//! Implements half-open intervals of UTC datetimes.
+
+use crate::time::UTCTransformations;
+use crate::UTCDatetime;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::time::Duration;
+
+/// UTC Interval.
+///
+/// A half-open interval of UTC datetimes, from `start` (inclusive) to `end` (exclusive).
+/// The start of an interval never exceeds its end.
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use core::time::Duration;
+/// use utc_dt::UTCDatetime;
+/// use utc_dt::interval::UTCInterval;
+///
+/// let start = UTCDatetime::try_from_iso_datetime("2023-06-15T09:00:00Z").unwrap();
+/// let end = UTCDatetime::try_from_iso_datetime("2023-06-15T17:00:00Z").unwrap();
+/// // UTC Interval from start and end datetimes
+/// let workday = UTCInterval::try_from_bounds(start, end).unwrap();
+/// // Get the duration of the interval
+/// assert_eq!(workday.duration(), Duration::from_secs(8 * 60 * 60));
+/// // Check whether a datetime occurs within the interval
+/// let lunch = UTCDatetime::try_from_iso_datetime("2023-06-15T12:30:00Z").unwrap();
+/// assert!(workday.contains(lunch));
+/// assert!(!workday.contains(end));
+/// // Check whether intervals overlap, and find their intersection
+/// let evening_start = UTCDatetime::try_from_iso_datetime("2023-06-15T16:00:00Z").unwrap();
+/// let evening_end = UTCDatetime::try_from_iso_datetime("2023-06-15T22:00:00Z").unwrap();
+/// let evening = UTCInterval::try_from_bounds(evening_start, evening_end).unwrap();
+/// assert!(workday.overlaps(&evening));
+/// let overtime = workday.intersection(&evening).unwrap();
+/// assert_eq!(overtime.duration(), Duration::from_secs(60 * 60));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UTCIntervalBounds"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UTCInterval {
+    start: UTCDatetime,
+    end: UTCDatetime,
+}
+
+/// Internal unvalidated bounds of a deserialized interval
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UTCIntervalBounds {
+    start: UTCDatetime,
+    end: UTCDatetime,
+}
+
+/// Deserialized intervals are validated as per [UTCInterval::try_from_bounds]
+#[cfg(feature = "serde")]
+impl TryFrom<UTCIntervalBounds> for UTCInterval {
+    type Error = UTCIntervalError;
+
+    fn try_from(bounds: UTCIntervalBounds) -> Result<Self, Self::Error> {
+        Self::try_from_bounds(bounds.start, bounds.end)
+    }
+}
+
+impl Display for UTCInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+impl UTCInterval {
+    /// Try to create a UTC interval from start (inclusive) and end (exclusive) datetimes.
+    ///
+    /// The start must not exceed the end.
+    pub fn try_from_bounds(start: UTCDatetime, end: UTCDatetime) -> Result<Self, UTCIntervalError> {
+        if start > end {
+            return Err(UTCIntervalError::StartAfterEnd(start, end));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Get the start of the interval (inclusive)
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> UTCDatetime {
+        self.start
+    }
+
+    /// Get the end of the interval (exclusive)
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> UTCDatetime {
+        self.end
+    }
+
+    /// Returns whether the interval is empty (start equal to end)
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Get the duration of the interval
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end.as_duration() - self.start.as_duration()
+    }
+
+    /// Returns whether the datetime occurs within the interval
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, datetime: UTCDatetime) -> bool {
+        self.start <= datetime && datetime < self.end
+    }
+
+    /// Returns whether the intervals share any datetime.
+    ///
+    /// Adjacent intervals (where one ends as the other starts) do not overlap.
+    #[inline]
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Get the interval of datetimes shared by both intervals.
+    ///
+    /// Returns [`None`] if the intervals do not overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Self {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// Get the interval covering both intervals.
+    ///
+    /// Returns [`None`] if the intervals are neither overlapping nor adjacent,
+    /// as the union would not be a single contiguous interval.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.start > other.end || other.start > self.end {
+            return None;
+        }
+        Some(Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+}
+
+/// Error type for UTCInterval methods
+#[derive(Debug, Clone)]
+pub enum UTCIntervalError {
+    /// Error raised due to the interval start exceeding the interval end
+    StartAfterEnd(UTCDatetime, UTCDatetime),
+}
+
+impl Display for UTCIntervalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StartAfterEnd(start, end) => {
+                write!(f, "interval start ({start}) exceeds interval end ({end})")
+            }
+        }
+    }
+}
+
+impl Error for UTCIntervalError {}
@@ -34,8 +34,13 @@
 //! - Provides constants useful for time transformations: [`utc-dt::constants`](https://docs.rs/utc-dt/latest/utc_dt/constants/index.html)
 //! - Nanosecond resolution.
 //! - Timestamps supporting standard math operators (`core::ops`)
-//! - `#![no_std]` and optional `alloc` support.
-//! - Optional serialization/deserialization of structures via `serde`
+//! - Datetime intervals supporting overlap detection, intersection and union: [`utc-dt::interval`](https://docs.rs/utc-dt/latest/utc_dt/interval/index.html)
+//! - Incremental datetime construction from individual components: [`utc-dt::builder`](https://docs.rs/utc-dt/latest/utc_dt/builder/index.html)
+//! - Constructors for common fixed-date holidays: [`utc-dt::holidays`](https://docs.rs/utc-dt/latest/utc_dt/holidays/index.html)
+//! - Monthly recurrence of dates, preserving the original day of the month: [`utc-dt::recurrence`](https://docs.rs/utc-dt/latest/utc_dt/recurrence/index.html)
+//! - Commonly used types and traits re-exported for glob import: [`utc-dt::prelude`](https://docs.rs/utc-dt/latest/utc_dt/prelude/index.html)
+//! - `#![no_std]` and optional `alloc` support. Error types implement `core::error::Error` on stable Rust, with or without `std`.
+//! - Optional serialization/deserialization of structures via `serde`, and zero-copy archiving via `rkyv`
 //!
 //! ## Examples (exhaustive)
 #![cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
@@ -200,7 +205,9 @@
 //! The [`std`, `alloc`] feature flags are enabled by default.
 //! - `std`: Enables methods that use the system clock via `std::time::SystemTime`. Enables `alloc`.
 //! - `alloc`: Enables methods that use allocated strings.
-//! - `serde`: Derives `serde::Serialize` and `serde::Deserialize` for all internal non-error types.
+//! - `serde`: Derives `serde::Serialize` and `serde::Deserialize` for all internal non-error types. Provides ISO datetime string (de)serialization via `utc_dt::serde`.
+//! - `rkyv`: Derives `rkyv::Archive`, `rkyv::Serialize` and `rkyv::Deserialize` for `UTCTimestamp`, `UTCDay`, `UTCTimeOfDay`, `UTCDate` and `UTCDatetime`, for zero-copy archives.
+//! - `arbitrary`: Implements `arbitrary::Arbitrary` for `UTCTimestamp`, `UTCDay`, `UTCTimeOfDay`, `UTCDate` and `UTCDatetime`, generating only valid, in-range values for fuzzing.
 //!
 //! ## References
 //! - [(Howard Hinnant, 2021) `chrono`-Compatible Low-Level Date Algorithms](http://howardhinnant.github.io/date_algorithms.html)
@@ -215,25 +222,43 @@
 #![warn(missing_docs)]
 #![warn(missing_debug_implementations)]
 #![warn(dead_code)]
+#![warn(clippy::must_use_candidate)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod builder;
 pub mod date;
+pub mod holidays;
+pub mod interval;
+pub mod prelude;
+pub mod recurrence;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod time;
 #[rustfmt::skip]
 pub mod constants;
 mod util;
 
-use crate::date::{UTCDate, UTCDateError};
-use crate::time::{UTCTimeOfDay, UTCTimeOfDayError, UTCTimestamp, UTCTransformations};
+use crate::constants::{NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_SECOND};
+use crate::date::{DateComponent, Days, Month, Months, UTCDate, UTCDateError, Years};
+use crate::interval::UTCIntervalError;
+use crate::time::{
+    UTCTimeOfDay, UTCTimeOfDayError, UTCTimestamp, UTCTimestampError, UTCTransformations, Weekday,
+};
 use core::error::Error;
-use core::fmt::{Display, Formatter};
+use core::fmt::{Display, Formatter, Write};
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Deref, Sub, SubAssign};
 use core::time::Duration;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
+#[cfg(feature = "std")]
+use std::time::SystemTimeError;
 use time::UTCDayErrOutOfRange;
 use util::StrWriter;
 
@@ -272,7 +297,12 @@ use util::StrWriter;
 /// assert_eq!(iso_datetime_str, "2023-06-15T10:18:08Z");
 /// ```
 ///
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct UTCDatetime {
     date: UTCDate,
@@ -280,8 +310,30 @@ pub struct UTCDatetime {
 }
 
 impl Display for UTCDatetime {
+    /// Formats the ISO datetime at nanosecond precision,
+    /// or at the formatter precision if specified (eg. `{:.3}`).
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}{}", self.date, self.tod)
+        write!(f, "{}", self.date)?;
+        Display::fmt(&self.tod, f)
+    }
+}
+
+impl PartialEq<str> for UTCDatetime {
+    /// Compares the ISO datetime representation at nanosecond precision
+    /// (equal to the `Display` representation) with the str, without allocation.
+    fn eq(&self, other: &str) -> bool {
+        const PRECISION: usize = UTCTimeOfDay::MAX_ISO_TOD_PRECISION;
+        let mut buf = [0; UTCTimeOfDay::iso_tod_len(PRECISION) + UTCDate::MAX_ISO_DATE_LEN];
+        match self.write_iso_datetime(&mut buf, PRECISION) {
+            Ok(written) => buf[..written] == *other.as_bytes(),
+            Err(_) => false,
+        }
+    }
+}
+
+impl PartialEq<&str> for UTCDatetime {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
     }
 }
 
@@ -309,16 +361,31 @@ impl UTCDatetime {
     /// The minimum length of an ISO datetime (in UTF8 characters)
     pub const MIN_ISO_DATETIME_LEN: usize = UTCTimeOfDay::MIN_ISO_TOD_LEN + UTCDate::ISO_DATE_LEN;
 
+    /// The maximum length of an ISO datetime (in UTF8 characters)
+    ///
+    /// Fits any datetime at any supported precision, including expanded years.
+    pub const MAX_ISO_DATETIME_LEN: usize =
+        UTCTimeOfDay::MAX_ISO_TOD_LEN + UTCDate::MAX_ISO_DATE_LEN;
+
     /// Create a datetime frome date and time-of-day components.
     #[inline]
+    #[must_use]
     pub const fn from_components(date: UTCDate, tod: UTCTimeOfDay) -> Self {
         Self { date, tod }
     }
 
+    /// Create a datetime at the start of the given date (`T00:00:00Z`).
+    #[inline]
+    #[must_use]
+    pub const fn from_date(date: UTCDate) -> Self {
+        Self::from_components(date, UTCTimeOfDay::ZERO)
+    }
+
     /// Get copy of the internal date and time-of-day components
     ///
     /// Returns tuple: `(date: UTCDate, tod: UTCTimeOfDay)`
     #[inline]
+    #[must_use]
     pub const fn as_components(&self) -> (UTCDate, UTCTimeOfDay) {
         (self.date, self.tod)
     }
@@ -327,32 +394,360 @@ impl UTCDatetime {
     ///
     /// Returns tuple: `(date: UTCDate, tod: UTCTimeOfDay)`
     #[inline]
+    #[must_use]
     pub const fn to_components(self) -> (UTCDate, UTCTimeOfDay) {
         (self.date, self.tod)
     }
 
+    /// Try to create a datetime from year, month, day, hours, minutes, seconds and subsecond (nanosecond) components.
+    ///
+    /// The argument order matches `chrono`'s `NaiveDate::from_ymd_opt` followed by `and_hms_nano_opt`,
+    /// easing conversion to and from `chrono` without a dependency.
+    /// The time of day is validated as per [UTCTimeOfDay::try_from_hhmmss].
+    pub fn try_from_ymd_hms_nanos(
+        year: u64,
+        month: u8,
+        day: u8,
+        hrs: u8,
+        mins: u8,
+        secs: u8,
+        subsec_ns: u32,
+    ) -> Result<Self, UTCDatetimeError> {
+        let date = UTCDate::try_from_components(year, month, day)?;
+        let tod = UTCTimeOfDay::try_from_hhmmss(hrs, mins, secs, subsec_ns)?;
+        Ok(Self::from_components(date, tod))
+    }
+
+    /// Get the datetime as year, month, day, hours, minutes, seconds and subsecond (nanosecond) components.
+    ///
+    /// Returns tuple: `(year: u64, month: u8, day: u8, hrs: u8, mins: u8, secs: u8, subsec_ns: u32)`
+    ///
+    /// The inverse of [UTCDatetime::try_from_ymd_hms_nanos].
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::UTCDatetime;
+    ///
+    /// let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z").unwrap();
+    /// assert_eq!(datetime.as_ymd_hms_nanos(), (2023, 6, 15, 10, 18, 8, 903_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_ymd_hms_nanos(&self) -> (u64, u8, u8, u8, u8, u8, u32) {
+        let (year, month, day) = self.date.as_components();
+        let (hrs, mins, secs) = self.tod.as_hhmmss();
+        (year, month, day, hrs, mins, secs, self.tod.as_subsec_ns())
+    }
+
     /// Get the internal date component.
     #[inline]
+    #[must_use]
     pub const fn as_date(&self) -> UTCDate {
         self.date
     }
 
     /// Get the internal time-of-day component.
     #[inline]
+    #[must_use]
     pub const fn as_tod(&self) -> UTCTimeOfDay {
         self.tod
     }
 
+    /// Convert to nanoseconds (`u128`) since the Unix Epoch.
+    ///
+    /// Lossless over the full range of UTC Datetimes,
+    /// see [UTCDatetime::try_from_nanos_u128] for the inverse.
+    #[inline]
+    #[must_use]
+    pub const fn as_nanos_u128(&self) -> u128 {
+        UTCTimestamp::from_day_and_tod(self.date.as_day(), self.tod).as_nanos()
+    }
+
+    /// Try to create a datetime from nanoseconds (`u128`) since the Unix Epoch.
+    ///
+    /// Supports the full range of UTC Datetimes, see [UTCDatetime::as_nanos_u128] for the inverse.
+    /// Raises the error of [UTCTimestamp::try_from_nanos_u128] for nanoseconds out of range.
+    pub fn try_from_nanos_u128(nanos: u128) -> Result<Self, UTCDatetimeError> {
+        let timestamp = UTCTimestamp::try_from_nanos_u128(nanos)?;
+        Ok(Self::from_timestamp(timestamp))
+    }
+
+    /// Get a stable 64-bit FNV-1a hash of the datetime.
+    ///
+    /// Equal to the hash of the datetime's [UTCTimestamp], see [UTCTimestamp::fnv1a_hash].
+    #[inline]
+    #[must_use]
+    pub const fn fnv1a_hash(&self) -> u64 {
+        UTCTimestamp::from_day_and_tod(self.date.as_day(), self.tod).fnv1a_hash()
+    }
+
+    /// Internal truncation of the time of day to a multiple of `unit_nanos`
+    #[inline]
+    const fn _truncate_tod(&self, unit_nanos: u64) -> Self {
+        let nanos = self.tod.as_nanos();
+        // SAFETY: truncated nanos do not exceed the original time of day nanos
+        let tod = unsafe { UTCTimeOfDay::from_nanos_unchecked(nanos - (nanos % unit_nanos)) };
+        Self::from_components(self.date, tod)
+    }
+
+    /// Get the datetime with subseconds truncated to whole seconds
+    #[inline]
+    #[must_use]
+    pub const fn truncate_to_secs(&self) -> Self {
+        self._truncate_tod(NANOS_PER_SECOND)
+    }
+
+    /// Get the datetime with subseconds truncated to whole milliseconds
+    #[inline]
+    #[must_use]
+    pub const fn truncate_to_millis(&self) -> Self {
+        self._truncate_tod(NANOS_PER_MILLI)
+    }
+
+    /// Get the datetime with subseconds truncated to whole microseconds
+    #[inline]
+    #[must_use]
+    pub const fn truncate_to_micros(&self) -> Self {
+        self._truncate_tod(NANOS_PER_MICRO)
+    }
+
+    /// Try to create a datetime from the local system time, truncated to whole seconds.
+    ///
+    /// See [UTCDatetime::truncate_to_secs].
+    #[cfg(feature = "std")]
+    pub fn try_now_secs() -> Result<Self, SystemTimeError> {
+        Ok(Self::try_from_system_time()?.truncate_to_secs())
+    }
+
+    /// Try to create a datetime from the local system time, truncated to whole milliseconds.
+    ///
+    /// See [UTCDatetime::truncate_to_millis].
+    #[cfg(feature = "std")]
+    pub fn try_now_millis() -> Result<Self, SystemTimeError> {
+        Ok(Self::try_from_system_time()?.truncate_to_millis())
+    }
+
+    /// Try to create a datetime from the local system time, truncated to whole microseconds.
+    ///
+    /// See [UTCDatetime::truncate_to_micros].
+    #[cfg(feature = "std")]
+    pub fn try_now_micros() -> Result<Self, SystemTimeError> {
+        Ok(Self::try_from_system_time()?.truncate_to_micros())
+    }
+
+    /// Get the duration from this datetime until `other`, returning [`None`]
+    /// if `other` precedes this datetime.
+    #[inline]
+    #[must_use]
+    pub fn duration_until(&self, other: UTCDatetime) -> Option<Duration> {
+        other
+            .as_timestamp()
+            .checked_duration_since(self.as_timestamp())
+    }
+
+    /// Get the duration elapsed from this datetime until the local system time, returning [`None`]
+    /// if this datetime is in the future, or the system time precedes the Unix Epoch.
+    ///
+    /// Useful for "expires in" and "age of" calculations.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::UTCDatetime;
+    ///
+    /// let past = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08Z").unwrap();
+    /// assert!(past.duration_until_now().is_some());
+    /// assert!(UTCDatetime::MAX.duration_until_now().is_none());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn duration_until_now(&self) -> Option<Duration> {
+        self.duration_until(Self::try_from_system_time().ok()?)
+    }
+
+    /// Checked addition of calendar months to the datetime, preserving the time of day.
+    /// Negative `months` are subtracted.
+    ///
+    /// The date is shifted as per [UTCDate::checked_add_months] and [UTCDate::checked_sub_months],
+    /// clamping the day to the last day of the resulting month.
+    /// Returns [`None`] if the resulting datetime would be outside [UTCDatetime::MIN] and [UTCDatetime::MAX].
+    #[must_use]
+    pub fn checked_add_months(self, months: i64) -> Option<Self> {
+        self._checked_map_date(|date| {
+            if months.is_negative() {
+                date.checked_sub_months(months.unsigned_abs())
+            } else {
+                date.checked_add_months(months as u64)
+            }
+        })
+    }
+
+    /// Checked addition of days to the datetime, preserving the time of day.
+    /// Negative `days` are subtracted.
+    ///
+    /// Returns [`None`] if the resulting datetime would be outside [UTCDatetime::MIN] and [UTCDatetime::MAX].
+    #[must_use]
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        self._checked_map_date(|date| {
+            if days.is_negative() {
+                date.checked_sub_days(days.unsigned_abs())
+            } else {
+                date.checked_add_days(days as u64)
+            }
+        })
+    }
+
+    /// Internal shift of the date, preserving the time of day.
+    /// Returns [`None`] if the shift fails or the result exceeds [UTCDatetime::MAX].
+    #[inline]
+    fn _checked_map_date(self, f: impl FnOnce(UTCDate) -> Option<UTCDate>) -> Option<Self> {
+        let datetime = Self::from_components(f(self.date)?, self.tod);
+        (datetime <= Self::MAX).then_some(datetime)
+    }
+
+    /// Checked addition of calendar years to the datetime, preserving the time of day.
+    /// Negative `years` are subtracted.
+    ///
+    /// February 29 is clamped to February 28 in non-leap years.
+    /// Returns [`None`] if the resulting datetime would be outside [UTCDatetime::MIN] and [UTCDatetime::MAX].
+    #[must_use]
+    pub fn checked_add_years(self, years: i64) -> Option<Self> {
+        self.checked_add_months(years.checked_mul(12)?)
+    }
+
+    /// Iterate `count` datetimes spaced by `step`, starting from this datetime.
+    ///
+    /// Saturates at [UTCDatetime::MAX], yielding it for any remaining steps.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use core::time::Duration;
+    /// use utc_dt::UTCDatetime;
+    ///
+    /// let start = UTCDatetime::try_from_iso_datetime("2023-06-15T10:00:00Z").unwrap();
+    /// let last = start.iter_step(Duration::from_secs(5 * 60), 4).last().unwrap();
+    /// assert_eq!(last, UTCDatetime::try_from_iso_datetime("2023-06-15T10:15:00Z").unwrap());
+    /// ```
+    pub fn iter_step(self, step: Duration, count: usize) -> impl Iterator<Item = UTCDatetime> {
+        core::iter::successors(Some(self.as_timestamp()), move |timestamp| {
+            Some(timestamp.saturating_add_duration(step))
+        })
+        .take(count)
+        .map(Self::from_timestamp)
+    }
+
+    /// Iterate datetimes spaced by `step`, starting from this datetime and preceding `end` (exclusive).
+    ///
+    /// Yields nothing if this datetime is not before `end`.
+    /// A zero `step` yields only this datetime.
+    pub fn step_until(self, end: UTCDatetime, step: Duration) -> impl Iterator<Item = UTCDatetime> {
+        let end = end.as_timestamp();
+        core::iter::successors(Some(self.as_timestamp()), move |timestamp| {
+            if step.is_zero() {
+                return None;
+            }
+            timestamp.checked_add_duration(step)
+        })
+        .take_while(move |timestamp| *timestamp < end)
+        .map(Self::from_timestamp)
+    }
+
+    /// Returns whether `self` and `other` are within `tolerance` (inclusive) of each other,
+    /// see [UTCTimestamp::approx_eq].
+    #[inline]
+    #[must_use]
+    pub const fn approx_eq(&self, other: UTCDatetime, tolerance: Duration) -> bool {
+        let timestamp = UTCTimestamp::from_day_and_tod(self.date.as_day(), self.tod);
+        let other = UTCTimestamp::from_day_and_tod(other.date.as_day(), other.tod);
+        timestamp.approx_eq(other, tolerance)
+    }
+
+    /// Check whether both datetimes fall on the same calendar date, regardless of time of day
+    #[inline]
+    #[must_use]
+    pub const fn is_same_day(&self, other: &Self) -> bool {
+        let (_, _, day) = self.date.as_components();
+        let (_, _, other_day) = other.date.as_components();
+        day == other_day && self.date.is_same_month(&other.date)
+    }
+
+    /// Get the datetime at the start of this datetime's day (`T00:00:00Z`)
+    #[inline]
+    #[must_use]
+    pub const fn start_of_day(&self) -> Self {
+        Self::from_date(self.date)
+    }
+
+    /// Get the datetime at the end of this datetime's day (`T23:59:59.999999999Z`)
+    ///
+    /// Saturates at [UTCDatetime::MAX].
+    #[must_use]
+    pub fn end_of_day(&self) -> Self {
+        Self::from_components(self.date, UTCTimeOfDay::MAX).min(Self::MAX)
+    }
+
+    /// Get the datetime at the start of the week containing this datetime,
+    /// where weeks begin on `first_day`.
+    ///
+    /// Returns the start of day of the latest `first_day` on or before this datetime.
+    /// Saturates at [UTCDatetime::MIN].
+    #[must_use]
+    pub fn start_of_week(&self, first_day: Weekday) -> Self {
+        let day = self.date.as_day();
+        let days_since_start = first_day.days_until(day.weekday());
+        let start_day = day.saturating_sub_u64(days_since_start as u64);
+        Self::from_date(UTCDate::from_day(start_day))
+    }
+
+    /// Get the datetime at the end of the week containing this datetime,
+    /// where weeks begin on `first_day`.
+    ///
+    /// Returns the end of day of the day preceding the next `first_day` after this datetime.
+    /// Saturates at [UTCDatetime::MAX].
+    #[must_use]
+    pub fn end_of_week(&self, first_day: Weekday) -> Self {
+        let day = self.date.as_day();
+        let days_until_end = 6 - first_day.days_until(day.weekday());
+        let end_day = day.saturating_add_u64(days_until_end as u64);
+        Self::from_components(UTCDate::from_day(end_day), UTCTimeOfDay::MAX).min(Self::MAX)
+    }
+
     /// Try parse datetime from str in the format:
     ///
     /// * `YYYY-MM-DDThh:mm:ssZ` or
     /// * `YYYY-MM-DDThh:mm:ss.nnnZ`
     ///
     /// Decimal precision of up to 9 places (inclusive) supported.
+    /// Expanded years are supported with a leading `+` (eg. `+10000-01-01T00:00:00Z`).
     ///
     /// Conforms to ISO 8601:
     /// <https://www.w3.org/TR/NOTE-datetime>
     pub fn try_from_iso_datetime(iso: &str) -> Result<Self, UTCDatetimeError> {
+        Self::try_from_iso_datetime_with_precision(iso).map(|(datetime, _)| datetime)
+    }
+
+    /// Try parse datetime from an ISO str as per [UTCDatetime::try_from_iso_datetime],
+    /// additionally returning the number of fractional second digits the str was written with.
+    ///
+    /// The precision may be passed to [UTCDatetime::write_iso_datetime] to re-emit
+    /// the datetime as it was received. A bare decimal point without fractional digits
+    /// (e.g. `"10:18:08.Z"`) is reported as precision 0, and is not re-emitted.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::UTCDatetime;
+    ///
+    /// let iso = "2023-06-15T10:18:08.903000Z";
+    /// let (datetime, precision) = UTCDatetime::try_from_iso_datetime_with_precision(iso).unwrap();
+    /// assert_eq!(precision, 6);
+    /// assert_eq!(datetime.as_iso_datetime(precision), iso);
+    /// ```
+    pub fn try_from_iso_datetime_with_precision(
+        iso: &str,
+    ) -> Result<(Self, usize), UTCDatetimeError> {
         let len = iso.len();
         if len < Self::MIN_ISO_DATETIME_LEN {
             return Err(UTCDatetimeError::InsufficientStrLen(
@@ -360,10 +755,41 @@ impl UTCDatetime {
                 Self::MIN_ISO_DATETIME_LEN,
             ));
         }
-        let (date_str, tod_str) = iso.split_at(10);
+        let date_len = if iso.starts_with('+') {
+            iso.find('T').unwrap_or(len)
+        } else {
+            UTCDate::ISO_DATE_LEN
+        };
+        let (date_str, tod_str) = iso.split_at(date_len);
         let date = UTCDate::try_from_iso_date(date_str)?;
-        let tod = UTCTimeOfDay::try_from_iso_tod(tod_str)?;
-        Ok(Self::from_components(date, tod))
+        let tod = UTCTimeOfDay::try_from_iso_tod(tod_str).map_err(|e| e._shift_offset(date_len))?;
+        // digits between the decimal point and the zone designator
+        let precision = tod_str
+            .len()
+            .saturating_sub(UTCTimeOfDay::MIN_ISO_TOD_LEN + 1);
+        Ok((Self::from_components(date, tod), precision))
+    }
+
+    /// Try parse an ISO datetime from the start of a str, as per [UTCDatetime::try_from_iso_datetime],
+    /// returning the datetime and the remaining unparsed suffix of the str.
+    ///
+    /// The datetime is terminated by its `Z` zone designator, so its length
+    /// (which varies with precision) need not be known.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::UTCDatetime;
+    ///
+    /// let (datetime, rest) = UTCDatetime::parse_prefix("2023-06-15T10:18:08.903Z INFO started").unwrap();
+    /// assert_eq!(datetime, UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z").unwrap());
+    /// assert_eq!(rest, " INFO started");
+    /// ```
+    pub fn parse_prefix(s: &str) -> Result<(Self, &str), UTCDatetimeError> {
+        // the date and time components contain no 'Z', the first occurrence terminates the datetime
+        let end = s.find('Z').map_or(s.len(), |idx| idx + 1);
+        let (iso, rest) = s.split_at(end);
+        Ok((Self::try_from_iso_datetime(iso)?, rest))
     }
 
     /// Return datetime as a string in the format:
@@ -377,15 +803,29 @@ impl UTCDatetime {
     /// Conforms to ISO 8601:
     /// <https://www.w3.org/TR/NOTE-datetime>
     #[cfg(feature = "alloc")]
+    #[must_use]
     pub fn as_iso_datetime(&self, precision: usize) -> String {
         self.date.as_iso_date() + &self.tod.as_iso_tod(precision)
     }
 
+    /// Write an ISO datetime to a generic `core::fmt::Write` sink in the format:
+    /// * Precision = `0`: `YYYY-MM-DDThh:mm:ssZ`
+    /// * Precision = `3`: `YYYY-MM-DDThh:mm:ss.nnnZ`
+    ///
+    /// Precision is limited to 9 decimal places (nanosecond precision).
+    ///
+    /// Conforms to ISO 8601:
+    /// <https://www.w3.org/TR/NOTE-datetime>
+    pub fn write_to<W: core::fmt::Write>(&self, w: &mut W, precision: usize) -> core::fmt::Result {
+        write!(w, "{}{:.precision$}", self.date, self.tod)
+    }
+
     /// Write an ISO datetime to a buffer in the format:
     /// * Precision = `0`: `YYYY-MM-DDThh:mm:ssZ`
     /// * Precision = `3`: `YYYY-MM-DDThh:mm:ss.nnnZ`
     ///
-    /// The buffer should have a minimum length as given by [UTCDatetime::iso_datetime_len].
+    /// The buffer should have a minimum length as given by [UTCDatetime::iso_datetime_len],
+    /// plus any additional characters required by an expanded year (see [UTCDate::iso_date_len]).
     ///
     /// A buffer of insufficient length will error ([UTCDatetimeError::InsufficientStrLen]).
     ///
@@ -398,21 +838,349 @@ impl UTCDatetime {
         buf: &mut [u8],
         precision: usize,
     ) -> Result<usize, UTCDatetimeError> {
-        let write_len = Self::iso_datetime_len(precision);
+        let write_len = UTCTimeOfDay::iso_tod_len(precision) + self.date.iso_date_len();
         if write_len > buf.len() {
             return Err(UTCDatetimeError::InsufficientStrLen(buf.len(), write_len));
         }
         let mut writer = StrWriter::new(&mut buf[..write_len]);
         self.date._write_iso_date_trunc(&mut writer);
-        self.tod._write_iso_tod_trunc(&mut writer);
+        self.tod._write_iso_tod(&mut writer, precision);
         Ok(writer.written)
     }
 
+    /// Get the fewest decimal places, of `0`, `3`, `6` or `9`, that represent the
+    /// subseconds of the datetime exactly. As chosen by `chrono` for RFC 3339 strs.
+    #[inline]
+    #[must_use]
+    pub const fn rfc3339_precision(&self) -> usize {
+        match self.tod.as_subsec_ns() {
+            0 => 0,
+            ns if ns % 1_000_000 == 0 => 3,
+            ns if ns % 1_000 == 0 => 6,
+            _ => 9,
+        }
+    }
+
+    /// Return datetime as an RFC 3339 string, matching the default output of `chrono`.
+    ///
+    /// Subseconds are written with the fewest of `0`, `3`, `6` or `9` decimal places
+    /// that represent the datetime exactly (see [UTCDatetime::rfc3339_precision]), with a `Z` suffix.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::UTCDatetime;
+    ///
+    /// let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903000Z").unwrap();
+    /// assert_eq!(datetime.to_rfc3339(), "2023-06-15T10:18:08.903Z");
+    /// let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.000Z").unwrap();
+    /// assert_eq!(datetime.to_rfc3339(), "2023-06-15T10:18:08Z");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        self.as_iso_datetime(self.rfc3339_precision())
+    }
+
+    /// Write an RFC 3339 datetime to a buffer, matching the default output of `chrono`.
+    /// The precision is chosen as per [UTCDatetime::rfc3339_precision].
+    ///
+    /// The buffer should have a minimum length as given by [UTCDatetime::iso_datetime_len]
+    /// at a precision of `9`, plus any additional characters required by an expanded year
+    /// (see [UTCDate::iso_date_len]).
+    ///
+    /// A buffer of insufficient length will error ([UTCDatetimeError::InsufficientStrLen]).
+    ///
+    /// Returns number of UTF8 characters (bytes) written
+    pub fn write_rfc3339(&self, buf: &mut [u8]) -> Result<usize, UTCDatetimeError> {
+        self.write_iso_datetime(buf, self.rfc3339_precision())
+    }
+
     /// Calculate the number of characters in an ISO datetime str
+    ///
+    /// Assumes a year of 4 digits. Datetimes with expanded years (exceeding `9999`)
+    /// require up to `UTCDate::MAX_ISO_DATE_LEN - UTCDate::ISO_DATE_LEN` additional characters.
     #[inline]
+    #[must_use]
     pub const fn iso_datetime_len(precision: usize) -> usize {
         UTCTimeOfDay::iso_tod_len(precision) + UTCDate::ISO_DATE_LEN
     }
+
+    /// Try to create a datetime from packed MS-DOS date and time words,
+    /// as used by FAT filesystems and ZIP archives.
+    ///
+    /// * `date`: bits 15-9 year since 1980, bits 8-5 month, bits 4-0 day
+    /// * `time`: bits 15-11 hours, bits 10-5 minutes, bits 4-0 seconds / 2
+    ///
+    /// MS-DOS datetimes have a resolution of 2 seconds, covering years 1980 to 2107.
+    pub fn try_from_dos_datetime(date: u16, time: u16) -> Result<Self, UTCDatetimeError> {
+        let year = 1980 + (date >> 9) as u64;
+        let month = ((date >> 5) & 0x0F) as u8;
+        let day = (date & 0x1F) as u8;
+        let hrs = (time >> 11) as u8;
+        let mins = ((time >> 5) & 0x3F) as u8;
+        let secs = ((time & 0x1F) * 2) as u8;
+        if hrs > 23 || mins > 59 || secs > 59 {
+            return Err(UTCDatetimeError::InvalidDosDatetime(date, time));
+        }
+        let date = UTCDate::try_from_components(year, month, day)?;
+        let tod = UTCTimeOfDay::try_from_hhmmss(hrs, mins, secs, 0)?;
+        Ok(Self::from_components(date, tod))
+    }
+
+    /// Convert to packed MS-DOS date and time words,
+    /// as used by FAT filesystems and ZIP archives.
+    ///
+    /// Returns tuple `(date: u16, time: u16)`, see [UTCDatetime::try_from_dos_datetime].
+    ///
+    /// Seconds are truncated to a resolution of 2 seconds.
+    /// Returns [`None`] if the year is not within 1980 to 2107 (inclusive).
+    #[must_use]
+    pub const fn to_dos_datetime(&self) -> Option<(u16, u16)> {
+        let (year, month, day) = self.date.as_components();
+        if year < 1980 || year > 2107 {
+            return None;
+        }
+        let (hrs, mins, secs) = self.tod.as_hhmmss();
+        let date = (((year - 1980) as u16) << 9) | ((month as u16) << 5) | day as u16;
+        let time = ((hrs as u16) << 11) | ((mins as u16) << 5) | (secs as u16 / 2);
+        Some((date, time))
+    }
+
+    /// Try parse datetime from a str in any of the supported formats.
+    ///
+    /// Formats are attempted in the order given by [UTCDatetimeFormat::ALL]:
+    /// * ISO 8601 extended: `YYYY-MM-DDThh:mm:ss.nnnZ`
+    /// * ISO 8601 basic: `YYYYMMDDThhmmss.nnnZ`
+    /// * RFC 3339 with offset: `YYYY-MM-DDThh:mm:ss.nnn+hh:mm`
+    /// * RFC 2822: `Thu, 15 Jun 2023 10:18:08 +0000`
+    ///
+    /// The first successful parse is returned.
+    /// Offsets are applied to normalize the datetime to UTC.
+    ///
+    /// If all formats fail, the returned error holds the error raised by each attempt.
+    pub fn parse_any(s: &str) -> Result<Self, ParseAnyError> {
+        let mut errors = UTCDatetimeFormat::ALL.map(UTCDatetimeError::InvalidFormat);
+        for (format, error) in UTCDatetimeFormat::ALL.iter().zip(errors.iter_mut()) {
+            match Self::_try_from_format(s, *format) {
+                Ok(datetime) => return Ok(datetime),
+                Err(e) => *error = e,
+            }
+        }
+        Err(ParseAnyError { errors })
+    }
+
+    /// Internal parse of datetime str of a given format
+    fn _try_from_format(s: &str, format: UTCDatetimeFormat) -> Result<Self, UTCDatetimeError> {
+        // all supported formats are ASCII, which allows byte slicing without panics
+        if !s.is_ascii() {
+            return Err(UTCDatetimeError::InvalidFormat(format));
+        }
+        match format {
+            UTCDatetimeFormat::IsoExtended => Self::try_from_iso_datetime(s),
+            UTCDatetimeFormat::IsoBasic => Self::_try_from_iso_basic(s),
+            UTCDatetimeFormat::Rfc3339 => Self::_try_from_rfc3339(s),
+            UTCDatetimeFormat::Rfc2822 => Self::_try_from_rfc2822(s),
+        }
+    }
+
+    /// Internal parse of an ASCII ISO 8601 basic format str `YYYYMMDDThhmmss.nnnZ`
+    fn _try_from_iso_basic(s: &str) -> Result<Self, UTCDatetimeError> {
+        const MIN_LEN: usize = 16;
+        let format_err = UTCDatetimeError::InvalidFormat(UTCDatetimeFormat::IsoBasic);
+        let len = s.len();
+        if len < MIN_LEN {
+            return Err(UTCDatetimeError::InsufficientStrLen(len, MIN_LEN));
+        }
+        let bytes = s.as_bytes();
+        if bytes[8] != b'T' || bytes[len - 1] != b'Z' {
+            return Err(format_err);
+        }
+        let year = UTCDate::_parse_year(&s[0..4])?;
+        let month: u8 = s[4..6].parse().map_err(UTCDateError::from)?;
+        let day: u8 = s[6..8].parse().map_err(UTCDateError::from)?;
+        let date = UTCDate::try_from_components(year, month, day)?;
+        let subsec_ns = match &s[15..(len - 1)] {
+            "" => 0,
+            frac if frac.starts_with('.') => UTCTimeOfDay::_subsec_ns_from_str(&frac[1..])?,
+            _ => return Err(format_err),
+        };
+        let tod = Self::_try_tod_from_hhmmss_strs(&s[9..11], &s[11..13], &s[13..15], subsec_ns)?;
+        Ok(Self::from_components(date, tod))
+    }
+
+    /// Internal parse of an ASCII RFC 3339 str `YYYY-MM-DDThh:mm:ss.nnn+hh:mm`
+    fn _try_from_rfc3339(s: &str) -> Result<Self, UTCDatetimeError> {
+        const MIN_LEN: usize = 20;
+        let format_err = UTCDatetimeError::InvalidFormat(UTCDatetimeFormat::Rfc3339);
+        let len = s.len();
+        if len < MIN_LEN {
+            return Err(UTCDatetimeError::InsufficientStrLen(len, MIN_LEN));
+        }
+        let bytes = s.as_bytes();
+        if !matches!(bytes[10], b'T' | b't') || bytes[13] != b':' || bytes[16] != b':' {
+            return Err(format_err);
+        }
+        let date = UTCDate::try_from_iso_date(&s[..10])?;
+        // split fractional seconds from the offset
+        let rem = &s[19..];
+        let offset_idx = rem
+            .find(|c: char| !(c == '.' || c.is_ascii_digit()))
+            .ok_or(format_err.clone())?;
+        let (frac, offset_str) = rem.split_at(offset_idx);
+        let subsec_ns = match frac {
+            "" => 0,
+            frac if frac.starts_with('.') => UTCTimeOfDay::_subsec_ns_from_str(&frac[1..])?,
+            _ => return Err(format_err),
+        };
+        let offset_secs = match offset_str.as_bytes() {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+                Self::_offset_secs_from_strs(*sign, &offset_str[1..3], &offset_str[4..6])
+                    .ok_or(format_err)?
+            }
+            _ => return Err(format_err),
+        };
+        let tod = Self::_try_tod_from_hhmmss_strs(&s[11..13], &s[14..16], &s[17..19], subsec_ns)?;
+        Self::from_components(date, tod)._try_apply_offset(offset_secs)
+    }
+
+    /// Internal parse of an ASCII RFC 2822 str `Thu, 15 Jun 2023 10:18:08 +0000`
+    fn _try_from_rfc2822(s: &str) -> Result<Self, UTCDatetimeError> {
+        const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+        let format_err = UTCDatetimeError::InvalidFormat(UTCDatetimeFormat::Rfc2822);
+        let mut tokens = s.split_ascii_whitespace().peekable();
+        // optional day of week
+        let weekday = match tokens.peek().and_then(|t| t.strip_suffix(',')) {
+            Some(name) => {
+                tokens.next();
+                let weekday = WEEKDAYS.iter().position(|w| w.eq_ignore_ascii_case(name));
+                Some(weekday.ok_or(format_err.clone())? as u8)
+            }
+            None => None,
+        };
+        let (Some(day_str), Some(month_str), Some(year_str), Some(time_str), Some(zone), None) = (
+            tokens.next(),
+            tokens.next(),
+            tokens.next(),
+            tokens.next(),
+            tokens.next(),
+            tokens.next(),
+        ) else {
+            return Err(format_err);
+        };
+        // date
+        if day_str.len() > 2 || year_str.len() != 4 {
+            return Err(format_err);
+        }
+        let day: u8 = day_str.parse().map_err(UTCDateError::from)?;
+        let month = Month::ALL
+            .into_iter()
+            .find(|m| m.abbreviation().eq_ignore_ascii_case(month_str))
+            .ok_or(format_err.clone())?
+            .as_u8();
+        let year = UTCDate::_parse_year(year_str)?;
+        let date = UTCDate::try_from_components(year, month, day)?;
+        if weekday.is_some_and(|w| w != date.as_day().as_weekday()) {
+            return Err(format_err);
+        }
+        // time of day, seconds are optional
+        let tod = match time_str.as_bytes() {
+            [_, _, b':', _, _] => {
+                Self::_try_tod_from_hhmmss_strs(&time_str[0..2], &time_str[3..5], "00", 0)?
+            }
+            [_, _, b':', _, _, b':', _, _] => Self::_try_tod_from_hhmmss_strs(
+                &time_str[0..2],
+                &time_str[3..5],
+                &time_str[6..8],
+                0,
+            )?,
+            _ => return Err(format_err),
+        };
+        // zone
+        let offset_secs = match zone.as_bytes() {
+            b"GMT" | b"UT" | b"Z" => 0,
+            [sign @ (b'+' | b'-'), _, _, _, _] => {
+                Self::_offset_secs_from_strs(*sign, &zone[1..3], &zone[3..5]).ok_or(format_err)?
+            }
+            _ => return Err(format_err),
+        };
+        Self::from_components(date, tod)._try_apply_offset(offset_secs)
+    }
+
+    /// Internal parse of time-of-day from hours, minutes and seconds strs
+    fn _try_tod_from_hhmmss_strs(
+        hrs: &str,
+        mins: &str,
+        secs: &str,
+        subsec_ns: u32,
+    ) -> Result<UTCTimeOfDay, UTCTimeOfDayError> {
+        let hrs: u8 = hrs.parse()?;
+        let mins: u8 = mins.parse()?;
+        let secs: u8 = secs.parse()?;
+        UTCTimeOfDay::try_from_hhmmss(hrs, mins, secs, subsec_ns)
+    }
+
+    /// Internal parse of a UTC offset (in seconds) from sign, hours and minutes
+    fn _offset_secs_from_strs(sign: u8, hrs: &str, mins: &str) -> Option<i32> {
+        let hrs: u8 = hrs.parse().ok()?;
+        let mins: u8 = mins.parse().ok()?;
+        if hrs > 23 || mins > 59 {
+            return None;
+        }
+        let secs = (hrs as i32 * 3600) + (mins as i32 * 60);
+        Some(if sign == b'-' { -secs } else { secs })
+    }
+
+    /// Internal normalization of a local datetime to UTC, given its offset (in seconds)
+    fn _try_apply_offset(self, offset_secs: i32) -> Result<Self, UTCDatetimeError> {
+        let timestamp = self.as_timestamp();
+        let offset = Duration::from_secs(offset_secs.unsigned_abs() as u64);
+        let utc = if offset_secs > 0 {
+            timestamp.checked_sub_duration(offset)
+        } else {
+            timestamp.checked_add_duration(offset)
+        };
+        match utc {
+            Some(utc) => Ok(Self::from_timestamp(utc)),
+            None => Err(UTCDatetimeError::OffsetOutOfRange(offset_secs)),
+        }
+    }
+}
+
+/// Datetime str formats supported by [UTCDatetime::parse_any]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UTCDatetimeFormat {
+    /// ISO 8601 extended format: `YYYY-MM-DDThh:mm:ss.nnnZ`
+    IsoExtended,
+    /// ISO 8601 basic format: `YYYYMMDDThhmmss.nnnZ`
+    IsoBasic,
+    /// RFC 3339 with offset: `YYYY-MM-DDThh:mm:ss.nnn+hh:mm`
+    Rfc3339,
+    /// RFC 2822: `Thu, 15 Jun 2023 10:18:08 +0000`
+    Rfc2822,
+}
+
+impl UTCDatetimeFormat {
+    /// All supported formats, in the order attempted by [UTCDatetime::parse_any]
+    pub const ALL: [Self; 4] = [
+        Self::IsoExtended,
+        Self::IsoBasic,
+        Self::Rfc3339,
+        Self::Rfc2822,
+    ];
+}
+
+impl Display for UTCDatetimeFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IsoExtended => write!(f, "ISO 8601 extended"),
+            Self::IsoBasic => write!(f, "ISO 8601 basic"),
+            Self::Rfc3339 => write!(f, "RFC 3339"),
+            Self::Rfc2822 => write!(f, "RFC 2822"),
+        }
+    }
 }
 
 impl UTCTransformations for UTCDatetime {
@@ -429,18 +1197,228 @@ impl UTCTransformations for UTCDatetime {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for UTCDatetime {
+    /// Generates datetimes within `[UTCDatetime::MIN, UTCDatetime::MAX]`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let date = UTCDate::arbitrary(u)?;
+        let tod = UTCTimeOfDay::arbitrary(u)?;
+        // the time of day of the maximum date is limited
+        Ok(Self::from_components(date, tod).min(Self::MAX))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(UTCDate::size_hint(depth), UTCTimeOfDay::size_hint(depth))
+    }
+}
+
+impl Add<Days> for UTCDatetime {
+    type Output = UTCDatetime;
+
+    fn add(self, rhs: Days) -> Self::Output {
+        self._checked_map_date(|date| date.checked_add_days(rhs.0))
+            .expect("overflow when adding days to datetime")
+    }
+}
+
+impl AddAssign<Days> for UTCDatetime {
+    fn add_assign(&mut self, rhs: Days) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Days> for UTCDatetime {
+    type Output = UTCDatetime;
+
+    fn sub(self, rhs: Days) -> Self::Output {
+        self._checked_map_date(|date| date.checked_sub_days(rhs.0))
+            .expect("overflow when subtracting days from datetime")
+    }
+}
+
+impl SubAssign<Days> for UTCDatetime {
+    fn sub_assign(&mut self, rhs: Days) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<Months> for UTCDatetime {
+    type Output = UTCDatetime;
+
+    fn add(self, rhs: Months) -> Self::Output {
+        self._checked_map_date(|date| date.checked_add_months(rhs.0))
+            .expect("overflow when adding months to datetime")
+    }
+}
+
+impl AddAssign<Months> for UTCDatetime {
+    fn add_assign(&mut self, rhs: Months) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Months> for UTCDatetime {
+    type Output = UTCDatetime;
+
+    fn sub(self, rhs: Months) -> Self::Output {
+        self._checked_map_date(|date| date.checked_sub_months(rhs.0))
+            .expect("overflow when subtracting months from datetime")
+    }
+}
+
+impl SubAssign<Months> for UTCDatetime {
+    fn sub_assign(&mut self, rhs: Months) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<Years> for UTCDatetime {
+    type Output = UTCDatetime;
+
+    fn add(self, rhs: Years) -> Self::Output {
+        self._checked_map_date(|date| date.checked_add_years(rhs.0))
+            .expect("overflow when adding years to datetime")
+    }
+}
+
+impl AddAssign<Years> for UTCDatetime {
+    fn add_assign(&mut self, rhs: Years) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Years> for UTCDatetime {
+    type Output = UTCDatetime;
+
+    fn sub(self, rhs: Years) -> Self::Output {
+        self._checked_map_date(|date| date.checked_sub_years(rhs.0))
+            .expect("overflow when subtracting years from datetime")
+    }
+}
+
+impl SubAssign<Years> for UTCDatetime {
+    fn sub_assign(&mut self, rhs: Years) {
+        *self = *self - rhs;
+    }
+}
+
 impl From<UTCTimestamp> for UTCDatetime {
     fn from(timestamp: UTCTimestamp) -> Self {
         Self::from_timestamp(timestamp)
     }
 }
 
+impl From<UTCDate> for UTCDatetime {
+    /// Creates a datetime at the start of the date, see [UTCDatetime::from_date].
+    fn from(date: UTCDate) -> Self {
+        Self::from_date(date)
+    }
+}
+
 impl From<Duration> for UTCDatetime {
     fn from(duration: Duration) -> Self {
         Self::from_duration(duration)
     }
 }
 
+/// Fixed-capacity ISO formatted str, stored on the stack.
+///
+/// Dereferences to `str`. Returned by infallible no-alloc formatting methods,
+/// such as [UTCDate::to_iso_array] and [UTCTimeOfDay::to_iso_array],
+/// and held by parse errors such as [UTCTimeOfDayError::InvalidComponent].
+#[derive(Clone, Copy)]
+pub struct IsoStr<const N: usize> {
+    buf: [u8; N],
+    // capacities do not exceed `u8::MAX`, keeping errors holding an `IsoStr` compact
+    len: u8,
+}
+
+impl<const N: usize> IsoStr<N> {
+    /// Internal creation of an ISO str, of length `len` (not exceeding `N`),
+    /// via a truncating write
+    pub(crate) fn _from_trunc_write(len: usize, write: impl FnOnce(&mut StrWriter)) -> Self {
+        const { assert!(N <= u8::MAX as usize, "IsoStr capacity exceeds u8::MAX") };
+        let mut buf = [0; N];
+        let mut writer = StrWriter::new(&mut buf[..len]);
+        write(&mut writer);
+        let len = writer.written as u8;
+        Self { buf, len }
+    }
+
+    /// Internal creation of an ISO str copied from `s`,
+    /// truncated to the capacity `N` on a char boundary
+    pub(crate) fn _from_str(s: &str) -> Self {
+        let mut len = s.len().min(N);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        Self::_from_trunc_write(len, |w| {
+            let _ = w.write_str(&s[..len]);
+        })
+    }
+
+    /// Get the ISO str
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: buffer is only written with valid UTF8 via `core::fmt`,
+        // ISO strs are ASCII and other strs are only truncated on a char boundary
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl<const N: usize> Deref for IsoStr<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for IsoStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Display for IsoStr<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for IsoStr<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> PartialEq for IsoStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for IsoStr<N> {}
+
+impl<const N: usize> Hash for IsoStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> PartialEq<str> for IsoStr<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for IsoStr<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 /// Error type for UTCDatetime methods
 #[derive(Debug, Clone)]
 pub enum UTCDatetimeError {
@@ -448,8 +1426,18 @@ pub enum UTCDatetimeError {
     UTCDate(UTCDateError),
     /// Error within UTC Time of Day
     UTCTimeOfDay(UTCTimeOfDayError),
+    /// Error within UTC Timestamp
+    UTCTimestamp(UTCTimestampError),
     /// Error raised due to insufficient length of input ISO datetime str
     InsufficientStrLen(usize, usize),
+    /// Error raised due to input str not matching the expected format
+    InvalidFormat(UTCDatetimeFormat),
+    /// Error raised due to a UTC offset (in seconds) normalizing the datetime out of range
+    OffsetOutOfRange(i32),
+    /// Error raised due to invalid MS-DOS date and time words
+    InvalidDosDatetime(u16, u16),
+    /// Error raised due to a date component not being provided
+    MissingComponent(DateComponent),
 }
 
 impl Display for UTCDatetimeError {
@@ -457,9 +1445,24 @@ impl Display for UTCDatetimeError {
         match self {
             Self::UTCDate(e) => e.fmt(f),
             Self::UTCTimeOfDay(e) => e.fmt(f),
+            Self::UTCTimestamp(e) => e.fmt(f),
             Self::InsufficientStrLen(l, m) => {
                 write!(f, "insufficient ISO datetime str len ({l}), {m} required")
             }
+            Self::InvalidFormat(format) => write!(f, "str does not match {format} format"),
+            Self::InvalidDosDatetime(d, t) => {
+                write!(
+                    f,
+                    "invalid MS-DOS datetime (date: {d:#06x}, time: {t:#06x})"
+                )
+            }
+            Self::MissingComponent(c) => write!(f, "missing {c} component"),
+            Self::OffsetOutOfRange(o) => {
+                write!(
+                    f,
+                    "applying UTC offset ({o}s) results in datetime out of range"
+                )
+            }
         }
     }
 }
@@ -469,6 +1472,7 @@ impl Error for UTCDatetimeError {
         match self {
             Self::UTCDate(e) => e.source(),
             Self::UTCTimeOfDay(e) => e.source(),
+            Self::UTCTimestamp(e) => e.source(),
             _ => None,
         }
     }
@@ -486,6 +1490,40 @@ impl From<UTCTimeOfDayError> for UTCDatetimeError {
     }
 }
 
+impl From<UTCTimestampError> for UTCDatetimeError {
+    fn from(value: UTCTimestampError) -> Self {
+        Self::UTCTimestamp(value)
+    }
+}
+
+/// Error type for [UTCDatetime::parse_any]
+///
+/// Holds the error raised by each format attempted.
+#[derive(Debug, Clone)]
+pub struct ParseAnyError {
+    errors: [UTCDatetimeError; UTCDatetimeFormat::ALL.len()],
+}
+
+impl ParseAnyError {
+    /// Iterate the formats attempted, paired with the error raised by each attempt
+    pub fn attempts(&self) -> impl Iterator<Item = (UTCDatetimeFormat, &UTCDatetimeError)> {
+        UTCDatetimeFormat::ALL.into_iter().zip(self.errors.iter())
+    }
+}
+
+impl Display for ParseAnyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unable to parse datetime, attempted formats:")?;
+        for (i, (format, error)) in self.attempts().enumerate() {
+            let sep = if i == 0 { "" } else { "," };
+            write!(f, "{sep} {format} ({error})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseAnyError {}
+
 /// UTC Datetime crate level error type
 #[derive(Debug, Clone)]
 pub enum UTCError {
@@ -497,6 +1535,12 @@ pub enum UTCError {
     UTCDay(UTCDayErrOutOfRange),
     /// Error within UTC Datetime
     UTCDatetime(UTCDatetimeError),
+    /// Error parsing a datetime of any format
+    ParseAny(ParseAnyError),
+    /// Error within UTC Interval
+    UTCInterval(UTCIntervalError),
+    /// Error within UTC Timestamp
+    UTCTimestamp(UTCTimestampError),
 }
 
 impl Display for UTCError {
@@ -506,6 +1550,9 @@ impl Display for UTCError {
             Self::UTCTimeOfDay(e) => e.fmt(f),
             Self::UTCDay(e) => e.fmt(f),
             Self::UTCDatetime(e) => e.fmt(f),
+            Self::ParseAny(e) => e.fmt(f),
+            Self::UTCInterval(e) => e.fmt(f),
+            Self::UTCTimestamp(e) => e.fmt(f),
         }
     }
 }
@@ -517,6 +1564,9 @@ impl Error for UTCError {
             Self::UTCTimeOfDay(e) => e.source(),
             Self::UTCDay(e) => e.source(),
             Self::UTCDatetime(e) => e.source(),
+            Self::ParseAny(e) => e.source(),
+            Self::UTCInterval(e) => e.source(),
+            Self::UTCTimestamp(e) => e.source(),
         }
     }
 }
@@ -544,3 +1594,49 @@ impl From<UTCDatetimeError> for UTCError {
         Self::UTCDatetime(value)
     }
 }
+
+impl From<ParseAnyError> for UTCError {
+    fn from(value: ParseAnyError) -> Self {
+        Self::ParseAny(value)
+    }
+}
+
+impl From<UTCIntervalError> for UTCError {
+    fn from(value: UTCIntervalError) -> Self {
+        Self::UTCInterval(value)
+    }
+}
+
+impl From<UTCTimestampError> for UTCError {
+    fn from(value: UTCTimestampError) -> Self {
+        Self::UTCTimestamp(value)
+    }
+}
+
+/// Pure methods are annotated `#[must_use]`, such that discarding the result is linted.
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use utc_dt::time::UTCTimestamp;
+///
+/// let timestamp = UTCTimestamp::ZERO;
+/// // does not mutate the timestamp in-place
+/// timestamp.saturating_add_secs(1);
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use utc_dt::date::UTCDate;
+///
+/// UTCDate::MIN.as_day();
+/// ```
+///
+/// ```rust
+/// #![deny(unused_must_use)]
+/// use utc_dt::time::UTCTimestamp;
+///
+/// let timestamp = UTCTimestamp::ZERO;
+/// let _ = timestamp.saturating_add_secs(1);
+/// ```
+#[cfg(doctest)]
+pub struct MustUseDoctests;
@@ -0,0 +1,23 @@
+//! Prelude module.
+//!
+//! Re-exports the commonly used types and traits of the crate,
+//! so that a single glob import brings them into scope.
+//!
+//! ## Examples
+#![cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#![cfg_attr(feature = "std", doc = "```rust")]
+//! use utc_dt::prelude::*;
+//!
+//! // trait methods of `UTCTransformations` are in scope
+//! let datetime = UTCDatetime::from_secs(1_700_000_000);
+//! assert_eq!(datetime.as_millis(), 1_700_000_000_000);
+//! let date = UTCDate::from_day(UTCDay::from_secs(1_700_000_000));
+//! assert_eq!(datetime.as_date(), date);
+//! assert_eq!(date.as_day().weekday(), Weekday::Tuesday);
+//! assert_eq!(datetime.as_timestamp(), UTCTimestamp::from_secs(1_700_000_000));
+//! assert_eq!(datetime.as_tod(), UTCTimeOfDay::try_from_hhmmss(22, 13, 20, 0).unwrap());
+//! ```
+
+pub use crate::date::{Month, UTCDate};
+pub use crate::time::{UTCDay, UTCTimeOfDay, UTCTimestamp, UTCTransformations, Weekday};
+pub use crate::UTCDatetime;
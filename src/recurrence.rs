@@ -0,0 +1,67 @@
+//! Recurrence module.
+//!
+//! Implements iterators over recurring UTC dates.
+
+use crate::date::UTCDate;
+use core::iter::FusedIterator;
+
+/// Monthly recurrence of a UTC date.
+///
+/// Iterates the same day of each month, beginning with (and including) the start date.
+/// Where the day does not exist in a month it is clamped to the last day of the month,
+/// but the original day of the month is restored in following months long enough to hold it.
+/// Iteration ends at [UTCDate::MAX].
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::date::UTCDate;
+/// use utc_dt::recurrence::MonthlyRecurrence;
+///
+/// let start = UTCDate::try_from_components(2023, 1, 31).unwrap();
+/// let dates: Vec<UTCDate> = MonthlyRecurrence::new(start).take(3).collect();
+/// // the day is clamped in February, and restored in March
+/// assert_eq!(dates[0], start);
+/// assert_eq!(dates[1], UTCDate::try_from_components(2023, 2, 28).unwrap());
+/// assert_eq!(dates[2], UTCDate::try_from_components(2023, 3, 31).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonthlyRecurrence {
+    start: UTCDate,
+    months: Option<u64>,
+}
+
+impl MonthlyRecurrence {
+    /// Create a monthly recurrence from the start date (inclusive).
+    #[inline]
+    #[must_use]
+    pub const fn new(start: UTCDate) -> Self {
+        Self {
+            start,
+            months: Some(0),
+        }
+    }
+
+    /// Get the start date of the recurrence, holding the original day of the month.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> UTCDate {
+        self.start
+    }
+}
+
+impl Iterator for MonthlyRecurrence {
+    type Item = UTCDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // offset from the start date, such that clamping does not accumulate
+        let date = self.months.and_then(|m| self.start.checked_add_months(m));
+        self.months = match date {
+            Some(_) => self.months.and_then(|m| m.checked_add(1)),
+            None => None,
+        };
+        date
+    }
+}
+
+impl FusedIterator for MonthlyRecurrence {}
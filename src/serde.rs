@@ -0,0 +1,185 @@
+//! Serde module.
+//!
+//! Provides `serde` (de)serialization of [UTCDatetime] as ISO 8601 datetime strings,
+//! for use with the `#[serde(with = "...")]` field attribute.
+//!
+//! Each submodule serializes with a fixed subsecond precision,
+//! and deserializes ISO datetimes of any precision (up to 9 decimal places).
+//!
+//! The [rfc3339] submodule instead provides `chrono` compatible RFC 3339 (de)serialization,
+//! accepting UTC offsets.
+//!
+//! ## Examples
+#![cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#![cfg_attr(feature = "std", doc = "```rust")]
+//! use serde::{Deserialize, Serialize};
+//! use utc_dt::UTCDatetime;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "utc_dt::serde::iso_datetime_millis")]
+//!     created: UTCDatetime,
+//! }
+//! ```
+
+use crate::{UTCDatetime, UTCDatetimeFormat};
+use core::fmt::Formatter;
+use serde::de::{Error, Visitor};
+use serde::{Deserializer, Serializer};
+
+/// Internal serialization of a datetime as an ISO str with the given precision
+fn _serialize<S: Serializer>(
+    datetime: &UTCDatetime,
+    serializer: S,
+    precision: usize,
+) -> Result<S::Ok, S::Error> {
+    let mut buf = [0; UTCDatetime::MAX_ISO_DATETIME_LEN];
+    // buffer fits any datetime
+    let written = datetime
+        .write_iso_datetime(&mut buf, precision)
+        .map_err(serde::ser::Error::custom)?;
+    // SAFETY: ISO datetimes are written as ASCII
+    let iso = unsafe { core::str::from_utf8_unchecked(&buf[..written]) };
+    serializer.serialize_str(iso)
+}
+
+/// Internal deserialization of a datetime from an ISO str of any precision
+fn _deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UTCDatetime, D::Error> {
+    deserializer.deserialize_str(IsoDatetimeVisitor)
+}
+
+/// Visitor for ISO datetime strs
+struct IsoDatetimeVisitor;
+
+impl Visitor<'_> for IsoDatetimeVisitor {
+    type Value = UTCDatetime;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "an ISO 8601 datetime str")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        UTCDatetime::try_from_iso_datetime(v).map_err(E::custom)
+    }
+}
+
+/// Visitor for RFC 3339 datetime strs
+struct Rfc3339DatetimeVisitor;
+
+impl Visitor<'_> for Rfc3339DatetimeVisitor {
+    type Value = UTCDatetime;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "an RFC 3339 datetime str")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        UTCDatetime::_try_from_format(v, UTCDatetimeFormat::Rfc3339).map_err(E::custom)
+    }
+}
+
+/// (De)serialize [UTCDatetime] as an ISO datetime with seconds precision (`YYYY-MM-DDThh:mm:ssZ`)
+pub mod iso_datetime_secs {
+    use super::*;
+
+    /// Serialize datetime as an ISO datetime str
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDatetime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        _serialize(datetime, serializer, 0)
+    }
+
+    /// Deserialize datetime from an ISO datetime str of any precision
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDatetime, D::Error> {
+        _deserialize(deserializer)
+    }
+}
+
+/// (De)serialize [UTCDatetime] as an ISO datetime with milliseconds precision (`YYYY-MM-DDThh:mm:ss.nnnZ`)
+pub mod iso_datetime_millis {
+    use super::*;
+
+    /// Serialize datetime as an ISO datetime str
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDatetime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        _serialize(datetime, serializer, 3)
+    }
+
+    /// Deserialize datetime from an ISO datetime str of any precision
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDatetime, D::Error> {
+        _deserialize(deserializer)
+    }
+}
+
+/// (De)serialize [UTCDatetime] as an ISO datetime with microseconds precision (`YYYY-MM-DDThh:mm:ss.nnnnnnZ`)
+pub mod iso_datetime_micros {
+    use super::*;
+
+    /// Serialize datetime as an ISO datetime str
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDatetime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        _serialize(datetime, serializer, 6)
+    }
+
+    /// Deserialize datetime from an ISO datetime str of any precision
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDatetime, D::Error> {
+        _deserialize(deserializer)
+    }
+}
+
+/// (De)serialize [UTCDatetime] as an ISO datetime with nanoseconds precision (`YYYY-MM-DDThh:mm:ss.nnnnnnnnnZ`)
+pub mod iso_datetime_nanos {
+    use super::*;
+
+    /// Serialize datetime as an ISO datetime str
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDatetime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        _serialize(datetime, serializer, 9)
+    }
+
+    /// Deserialize datetime from an ISO datetime str of any precision
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDatetime, D::Error> {
+        _deserialize(deserializer)
+    }
+}
+
+/// (De)serialize [UTCDatetime] as an RFC 3339 datetime, compatible with `chrono::DateTime<Utc>`
+///
+/// Serializes as per [UTCDatetime::write_rfc3339], with the fewest of `0`, `3`, `6` or `9` decimal places
+/// that represent the datetime exactly (eg. `2023-06-15T10:18:08.903Z`), matching the output of `chrono`.
+///
+/// Deserializes RFC 3339 datetimes with either a `Z` suffix or a numeric UTC offset
+/// (eg. `2023-06-15T10:18:08.903+00:00`), normalizing offsets to UTC.
+pub mod rfc3339 {
+    use super::*;
+
+    /// Serialize datetime as an RFC 3339 datetime str
+    pub fn serialize<S: Serializer>(
+        datetime: &UTCDatetime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        _serialize(datetime, serializer, datetime.rfc3339_precision())
+    }
+
+    /// Deserialize datetime from an RFC 3339 datetime str, normalizing any UTC offset
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<UTCDatetime, D::Error> {
+        deserializer.deserialize_str(Rfc3339DatetimeVisitor)
+    }
+}
@@ -3,18 +3,29 @@
 //! Implements core time concepts via UTC Timestamps, UTC Days and UTC Time-of-Days.
 
 use crate::constants::*;
-use crate::util::StrWriter;
+use crate::util::{fnv1a_64, StrWriter};
+use crate::{IsoStr, UTCDatetime};
+use core::borrow::Borrow;
 use core::error::Error;
-use core::fmt::{Display, Formatter, Write};
+use core::fmt::{Display, Formatter, LowerHex, UpperHex, Write};
+use core::iter::Sum;
 use core::num::ParseIntError;
 use core::ops::*;
+use core::str::FromStr;
 use core::time::Duration;
 
 #[cfg(feature = "alloc")]
 use alloc::{format, string::String};
 
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
 #[cfg(feature = "std")]
-use std::time::{SystemTime, SystemTimeError};
+use std::time::{Instant, SystemTime, SystemTimeError};
+
+/// Nanoseconds per Windows FILETIME interval
+const FILETIME_INTERVAL_NANOS: u64 = 100;
+/// Windows FILETIME intervals per second
+const FILETIME_INTERVALS_PER_SECOND: u64 = NANOS_PER_SECOND / FILETIME_INTERVAL_NANOS;
 
 /// UTC Timestamp.
 ///
@@ -61,6 +72,11 @@ use std::time::{SystemTime, SystemTimeError};
 /// ```
 ///
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct UTCTimestamp(Duration);
 
@@ -75,17 +91,33 @@ impl UTCTimestamp {
     /// Equal to `November 9, 584_554_051_223`
     pub const MAX: UTCTimestamp = UTCTimestamp(Duration::MAX);
 
+    /// Seconds from the NTP prime epoch (`1900-01-01T00:00:00Z`) to the Unix Epoch
+    pub const NTP_EPOCH_OFFSET_SECS: u64 = 2_208_988_800;
+
+    /// Windows FILETIME of the Unix Epoch, in 100-nanosecond intervals since `1601-01-01T00:00:00Z`
+    pub const FILETIME_UNIX_EPOCH: u64 = 116_444_736_000_000_000;
+
     /// Create a UTC Timestamp from UTC day
     #[inline]
+    #[must_use]
     pub const fn from_day(day: UTCDay) -> Self {
-        let secs = day.0 * SECONDS_PER_DAY;
+        // `UTCDay::MAX * SECONDS_PER_DAY` is within `u64::MAX`, saturation only guards
+        // against days beyond the maximum (eg. created by `UTCDay::from_u64_unchecked`)
+        let secs = day.0.saturating_mul(SECONDS_PER_DAY);
         Self(Duration::from_secs(secs))
     }
 
     /// Create a UTC Timestamp from UTC day and time-of-day components
+    ///
+    /// Saturates at [UTCTimestamp::MAX] seconds for times of day beyond that
+    /// of [UTCTimestamp::MAX] on [UTCDay::MAX].
     #[inline]
+    #[must_use]
     pub const fn from_day_and_tod(day: UTCDay, tod: UTCTimeOfDay) -> Self {
-        let secs = (day.0 * SECONDS_PER_DAY).saturating_add(tod.as_secs() as u64);
+        let secs = day
+            .0
+            .saturating_mul(SECONDS_PER_DAY)
+            .saturating_add(tod.as_secs() as u64);
         let subsec_ns = tod.as_subsec_ns();
         Self(Duration::new(secs, subsec_ns))
     }
@@ -97,27 +129,65 @@ impl UTCTimestamp {
         Ok(UTCTimestamp(duration))
     }
 
+    /// Project a monotonic `Instant` onto the UTC wall clock,
+    /// given an anchor pair of an `Instant` and a UTC Timestamp captured at the same moment.
+    ///
+    /// The elapsed time between `anchor_instant` and `instant` is applied to `anchor_ts`,
+    /// allowing high-resolution monotonic measurements to be expressed in UTC.
+    /// Instants preceding the anchor are projected backwards.
+    /// Saturates at [UTCTimestamp::ZERO] and [UTCTimestamp::MAX].
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use std::time::Instant;
+    /// use utc_dt::time::UTCTimestamp;
+    ///
+    /// // capture the anchor pair once
+    /// let anchor_instant = Instant::now();
+    /// let anchor_ts = UTCTimestamp::try_from_system_time().unwrap();
+    /// // later monotonic measurements
+    /// let instant = Instant::now();
+    /// let timestamp = UTCTimestamp::since_instant_anchor(instant, anchor_instant, anchor_ts);
+    /// assert!(timestamp >= anchor_ts);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn since_instant_anchor(
+        instant: Instant,
+        anchor_instant: Instant,
+        anchor_ts: UTCTimestamp,
+    ) -> Self {
+        match instant.checked_duration_since(anchor_instant) {
+            Some(elapsed) => anchor_ts.saturating_add_duration(elapsed),
+            None => anchor_ts.saturating_sub_duration(anchor_instant.duration_since(instant)),
+        }
+    }
+
     /// Create UTC Timestamp from a duration.
     /// Constant evaluation alternative to `From<Duration>`.
     #[inline]
+    #[must_use]
     pub const fn from_duration(d: Duration) -> Self {
         Self(d)
     }
 
     /// UTC Timestamp as internal Duration since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn as_duration(&self) -> Duration {
         self.0
     }
 
     /// Consume UTC Timestamp into the internal Duration since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn to_duration(self) -> Duration {
         self.0
     }
 
     /// Get the UTC time-of-day in nanoseconds.
     #[inline]
+    #[must_use]
     pub const fn as_tod(&self) -> UTCTimeOfDay {
         let ns = ((self.0.as_secs() % SECONDS_PER_DAY) * NANOS_PER_SECOND)
             + (self.0.subsec_nanos() as u64);
@@ -127,61 +197,272 @@ impl UTCTimestamp {
 
     /// Get the number of UTC days since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn as_day(&self) -> UTCDay {
         UTCDay(self.0.as_secs() / SECONDS_PER_DAY)
     }
 
+    /// Try to create a UTC Timestamp from whole seconds since the Unix Epoch
+    /// and subsecond nanoseconds.
+    ///
+    /// Subsecond nanoseconds must be less than one second (`1_000_000_000`),
+    /// see [UTCTimestamp::as_parts] for the inverse.
+    pub const fn try_from_parts(secs: u64, subsec_nanos: u32) -> Result<Self, UTCTimestampError> {
+        if subsec_nanos >= NANOS_PER_SECOND as u32 {
+            return Err(UTCTimestampError::ExcessSubsecNanos(subsec_nanos));
+        }
+        Ok(Self(Duration::new(secs, subsec_nanos)))
+    }
+
+    /// Get the whole seconds since the Unix Epoch and the subsecond nanoseconds
+    /// of the UTC Timestamp.
+    ///
+    /// Unlike [UTCTimestamp::as_nanos], the split avoids `u128` arithmetic,
+    /// which may be costly or unsupported on some targets.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimestamp;
+    ///
+    /// let timestamp = UTCTimestamp::from_millis(1_686_824_288_903);
+    /// assert_eq!(timestamp.as_parts(), (1_686_824_288, 903_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_parts(&self) -> (u64, u32) {
+        (self.0.as_secs(), self.0.subsec_nanos())
+    }
+
+    /// Split the UTC Timestamp into the number of UTC days since the Unix Epoch,
+    /// and the `Duration` elapsed since the start of that day.
+    ///
+    /// Equivalent to calling [UTCTimestamp::as_day] and [UTCTimestamp::as_tod],
+    /// in a single division pass.
+    #[inline]
+    #[must_use]
+    pub const fn split_day(&self) -> (UTCDay, Duration) {
+        let secs = self.0.as_secs();
+        let day = secs / SECONDS_PER_DAY;
+        let intraday_secs = secs - (day * SECONDS_PER_DAY);
+        (
+            UTCDay(day),
+            Duration::new(intraday_secs, self.0.subsec_nanos()),
+        )
+    }
+
+    /// Create UTC Timestamp from a 64-bit NTP timestamp.
+    ///
+    /// The high 32 bits hold seconds since the NTP epoch (`1900-01-01T00:00:00Z`),
+    /// the low 32 bits hold fractional seconds in units of 2^-32 seconds.
+    ///
+    /// NTP timestamps wrap every 2^32 seconds (~136 years). Since UTC timestamps
+    /// cannot precede the Unix Epoch, NTP seconds before the Unix Epoch are interpreted
+    /// as NTP era 1 (beginning `2036-02-07T06:28:16Z`).
+    /// The supported window is therefore `1970-01-01T00:00:00Z` to `2106-02-07T06:28:16Z` (exclusive).
+    ///
+    /// Fractional seconds are truncated to nanosecond resolution.
+    #[must_use]
+    pub const fn from_ntp(ntp: u64) -> Self {
+        let ntp_secs = ntp >> 32;
+        let frac = ntp & (u32::MAX as u64);
+        let secs = if ntp_secs >= Self::NTP_EPOCH_OFFSET_SECS {
+            ntp_secs - Self::NTP_EPOCH_OFFSET_SECS
+        } else {
+            // NTP era 1
+            ntp_secs + (1 << 32) - Self::NTP_EPOCH_OFFSET_SECS
+        };
+        let subsec_ns = ((frac * NANOS_PER_SECOND) >> 32) as u32;
+        Self(Duration::new(secs, subsec_ns))
+    }
+
+    /// Convert to a 64-bit NTP timestamp.
+    ///
+    /// The high 32 bits hold seconds since the NTP epoch (`1900-01-01T00:00:00Z`),
+    /// the low 32 bits hold fractional seconds in units of 2^-32 seconds.
+    ///
+    /// Fractional seconds are rounded up, such that [UTCTimestamp::from_ntp] recovers
+    /// the exact timestamp. Timestamps beyond the supported window (see [UTCTimestamp::from_ntp])
+    /// wrap into the corresponding NTP era.
+    #[must_use]
+    pub const fn to_ntp(&self) -> u64 {
+        let ntp_secs = self.0.as_secs().wrapping_add(Self::NTP_EPOCH_OFFSET_SECS) as u32 as u64;
+        let subsec_ns = self.0.subsec_nanos() as u64;
+        let frac = (subsec_ns << 32).div_ceil(NANOS_PER_SECOND);
+        (ntp_secs << 32) | frac
+    }
+
+    /// Try to create UTC Timestamp from a Windows FILETIME.
+    ///
+    /// FILETIME counts 100-nanosecond intervals since `1601-01-01T00:00:00Z`.
+    /// FILETIME values preceding the Unix Epoch raise an error.
+    pub const fn try_from_filetime(ft: u64) -> Result<Self, UTCTimestampError> {
+        if ft < Self::FILETIME_UNIX_EPOCH {
+            return Err(UTCTimestampError::FiletimeBeforeEpoch(ft));
+        }
+        let intervals = ft - Self::FILETIME_UNIX_EPOCH;
+        let secs = intervals / FILETIME_INTERVALS_PER_SECOND;
+        let subsec_ns = (intervals % FILETIME_INTERVALS_PER_SECOND) * FILETIME_INTERVAL_NANOS;
+        Ok(Self(Duration::new(secs, subsec_ns as u32)))
+    }
+
+    /// Convert to a Windows FILETIME.
+    ///
+    /// FILETIME counts 100-nanosecond intervals since `1601-01-01T00:00:00Z`.
+    /// Subsecond precision is truncated to 100 nanoseconds.
+    /// Saturates at `u64::MAX` for timestamps beyond the range of FILETIME (year 60056).
+    #[must_use]
+    pub const fn to_filetime(&self) -> u64 {
+        let intervals = (self.0.as_nanos() / FILETIME_INTERVAL_NANOS as u128)
+            + Self::FILETIME_UNIX_EPOCH as u128;
+        if intervals > u64::MAX as u128 {
+            u64::MAX
+        } else {
+            intervals as u64
+        }
+    }
+
+    /// Get a stable 64-bit FNV-1a hash of the UTC Timestamp.
+    ///
+    /// Unlike the derived `Hash`, the output is stable across platforms and Rust versions,
+    /// computed over the nanoseconds since the Unix Epoch as little-endian `u128` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn fnv1a_hash(&self) -> u64 {
+        fnv1a_64(&self.as_nanos().to_le_bytes())
+    }
+
+    /// The length of the byte representation of a UTC Timestamp
+    pub const BYTES_LEN: usize = 12;
+
+    /// Get the little-endian byte representation of the UTC Timestamp.
+    ///
+    /// Bytes `[0, 8)` hold the seconds since the Unix Epoch as a little-endian `u64`,
+    /// bytes `[8, 12)` hold the subsecond nanoseconds as a little-endian `u32`.
+    #[must_use]
+    pub const fn to_le_bytes(&self) -> [u8; Self::BYTES_LEN] {
+        let secs = self.0.as_secs().to_le_bytes();
+        let nanos = self.0.subsec_nanos().to_le_bytes();
+        [
+            secs[0], secs[1], secs[2], secs[3], secs[4], secs[5], secs[6], secs[7], nanos[0],
+            nanos[1], nanos[2], nanos[3],
+        ]
+    }
+
+    /// Get the big-endian byte representation of the UTC Timestamp.
+    ///
+    /// Bytes `[0, 8)` hold the seconds since the Unix Epoch as a big-endian `u64`,
+    /// bytes `[8, 12)` hold the subsecond nanoseconds as a big-endian `u32`.
+    #[must_use]
+    pub const fn to_be_bytes(&self) -> [u8; Self::BYTES_LEN] {
+        let secs = self.0.as_secs().to_be_bytes();
+        let nanos = self.0.subsec_nanos().to_be_bytes();
+        [
+            secs[0], secs[1], secs[2], secs[3], secs[4], secs[5], secs[6], secs[7], nanos[0],
+            nanos[1], nanos[2], nanos[3],
+        ]
+    }
+
+    /// Try to create a UTC Timestamp from its little-endian byte representation,
+    /// see [UTCTimestamp::to_le_bytes].
+    ///
+    /// The subsecond nanoseconds must not exceed one second.
+    pub const fn try_from_le_bytes(
+        bytes: [u8; Self::BYTES_LEN],
+    ) -> Result<Self, UTCTimestampError> {
+        let secs = u64::from_le_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]);
+        let nanos = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+        Self::try_from_parts(secs, nanos)
+    }
+
+    /// Try to create a UTC Timestamp from its big-endian byte representation,
+    /// see [UTCTimestamp::to_be_bytes].
+    ///
+    /// The subsecond nanoseconds must not exceed one second.
+    pub const fn try_from_be_bytes(
+        bytes: [u8; Self::BYTES_LEN],
+    ) -> Result<Self, UTCTimestampError> {
+        let secs = u64::from_be_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]);
+        let nanos = u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+        Self::try_from_parts(secs, nanos)
+    }
+
     /// Create UTC Timestamp from seconds since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn from_secs(secs: u64) -> Self {
         UTCTimestamp(Duration::from_secs(secs))
     }
 
     /// Convert to seconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn as_secs(&self) -> u64 {
         self.0.as_secs()
     }
 
     /// Create UTC Timestamp from milliseconds since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn from_millis(millis: u64) -> Self {
         UTCTimestamp(Duration::from_millis(millis))
     }
 
     /// Convert to milliseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn as_millis(&self) -> u128 {
         self.0.as_millis()
     }
 
     /// Create UTC Timestamp from microseconds since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn from_micros(micros: u64) -> Self {
         UTCTimestamp(Duration::from_micros(micros))
     }
 
     /// Convert to microseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn as_micros(&self) -> u128 {
         self.0.as_micros()
     }
 
     /// Create UTC Timestamp from nanoseconds since the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn from_nanos(nanos: u64) -> Self {
         UTCTimestamp(Duration::from_nanos(nanos))
     }
 
     /// Convert to seconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     pub const fn as_nanos(&self) -> u128 {
         self.0.as_nanos()
     }
 
+    /// Try to create UTC Timestamp from nanoseconds (`u128`) since the Unix Epoch.
+    ///
+    /// Supports the full range of UTC Timestamps, see [UTCTimestamp::as_nanos] for the inverse.
+    pub const fn try_from_nanos_u128(nanos: u128) -> Result<Self, UTCTimestampError> {
+        let secs = nanos / NANOS_PER_SECOND as u128;
+        if secs > u64::MAX as u128 {
+            return Err(UTCTimestampError::ExcessNanos(nanos));
+        }
+        let subsec_ns = (nanos % NANOS_PER_SECOND as u128) as u32;
+        Ok(Self(Duration::new(secs as u64, subsec_ns)))
+    }
+
     /// Checked `UTCTimestamp` addition. Computes `self + other`, returning [`None`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_add(self, rhs: UTCTimestamp) -> Option<UTCTimestamp> {
         match self.0.checked_add(rhs.0) {
             Some(duration) => Some(UTCTimestamp(duration)),
@@ -192,6 +473,7 @@ impl UTCTimestamp {
     /// Checked `UTCTimestamp` addition with `Duration`. Computes `self + other`, returning [`None`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_add_duration(self, rhs: Duration) -> Option<UTCTimestamp> {
         match self.0.checked_add(rhs) {
             Some(duration) => Some(UTCTimestamp(duration)),
@@ -199,9 +481,26 @@ impl UTCTimestamp {
         }
     }
 
+    /// Checked `UTCTimestamp` addition with `UTCDay`, offsetting by whole days.
+    /// Computes `self + other`, returning [`None`] if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add_days(self, rhs: UTCDay) -> Option<UTCTimestamp> {
+        self.checked_add(UTCTimestamp::from_day(rhs))
+    }
+
+    /// Checked `UTCTimestamp` addition with `UTCTimeOfDay`, offsetting by the intraday nanoseconds.
+    /// Computes `self + other`, returning [`None`] if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add_tod(self, rhs: UTCTimeOfDay) -> Option<UTCTimestamp> {
+        self.checked_add_duration(Duration::from_nanos(rhs.as_nanos()))
+    }
+
     /// Saturating `UTCTimestamp` addition. Computes `self + other`, returning [`UTCTimestamp::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_add(self, rhs: UTCTimestamp) -> UTCTimestamp {
         match self.checked_add(rhs) {
             Some(res) => res,
@@ -212,6 +511,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` addition with `Duration`. Computes `self + other`, returning [`UTCTimestamp::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_add_duration(self, rhs: Duration) -> UTCTimestamp {
         match self.checked_add_duration(rhs) {
             Some(res) => res,
@@ -222,6 +522,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` addition with nanoseconds. Computes `self + other`, returning [`UTCTimestamp::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_add_nanos(self, rhs: u64) -> UTCTimestamp {
         self.saturating_add(UTCTimestamp::from_nanos(rhs))
     }
@@ -229,6 +530,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` addition with microseconds. Computes `self + other`, returning [`UTCTimestamp::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_add_micros(self, rhs: u64) -> UTCTimestamp {
         self.saturating_add(UTCTimestamp::from_micros(rhs))
     }
@@ -236,6 +538,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` addition with milliseconds. Computes `self + other`, returning [`UTCTimestamp::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_add_millis(self, rhs: u64) -> UTCTimestamp {
         self.saturating_add(UTCTimestamp::from_millis(rhs))
     }
@@ -243,13 +546,36 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` addition with seconds. Computes `self + other`, returning [`UTCTimestamp::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_add_secs(self, rhs: u64) -> UTCTimestamp {
         self.saturating_add(UTCTimestamp::from_secs(rhs))
     }
 
+    /// Saturating `UTCTimestamp` addition, reporting saturation. Computes `self + other`,
+    /// returning [`UTCTimestamp::MAX`] and [`Saturation::Max`] if overflow occurred.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::{Saturation, UTCTimestamp};
+    ///
+    /// let (res, saturation) = UTCTimestamp::MAX.saturating_add_reporting(UTCTimestamp::from_secs(1));
+    /// assert_eq!(res, UTCTimestamp::MAX);
+    /// assert_eq!(saturation, Saturation::Max);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add_reporting(self, rhs: UTCTimestamp) -> (UTCTimestamp, Saturation) {
+        match self.checked_add(rhs) {
+            Some(res) => (res, Saturation::None),
+            None => (UTCTimestamp::MAX, Saturation::Max),
+        }
+    }
+
     /// Checked `UTCTimestamp` subtraction. Computes `self - other`, returning [`None`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_sub(self, rhs: UTCTimestamp) -> Option<UTCTimestamp> {
         match self.0.checked_sub(rhs.0) {
             Some(duration) => Some(UTCTimestamp(duration)),
@@ -260,6 +586,7 @@ impl UTCTimestamp {
     /// Checked `UTCTimestamp` subtraction with `Duration`. Computes `self - other`, returning [`None`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_sub_duration(self, rhs: Duration) -> Option<UTCTimestamp> {
         match self.0.checked_sub(rhs) {
             Some(duration) => Some(UTCTimestamp(duration)),
@@ -270,6 +597,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` subtraction. Computes `self - other`, returning [`UTCTimestamp::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub(self, rhs: UTCTimestamp) -> UTCTimestamp {
         match self.checked_sub(rhs) {
             Some(res) => res,
@@ -277,9 +605,21 @@ impl UTCTimestamp {
         }
     }
 
+    /// Saturating `UTCTimestamp` subtraction, reporting saturation. Computes `self - other`,
+    /// returning [`UTCTimestamp::ZERO`] and [`Saturation::Min`] if the result would be negative.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub_reporting(self, rhs: UTCTimestamp) -> (UTCTimestamp, Saturation) {
+        match self.checked_sub(rhs) {
+            Some(res) => (res, Saturation::None),
+            None => (UTCTimestamp::ZERO, Saturation::Min),
+        }
+    }
+
     /// Saturating `UTCTimestamp` subtraction with `Duration`. Computes `self - other`, returning [`UTCTimestamp::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub_duration(self, rhs: Duration) -> UTCTimestamp {
         match self.checked_sub_duration(rhs) {
             Some(res) => res,
@@ -290,6 +630,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` subtraction with nanoseconds. Computes `self + other`, returning [`UTCTimestamp::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub_nanos(self, rhs: u64) -> UTCTimestamp {
         self.saturating_sub(UTCTimestamp::from_nanos(rhs))
     }
@@ -297,6 +638,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` subtraction with microseconds. Computes `self + other`, returning [`UTCTimestamp::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub_micros(self, rhs: u64) -> UTCTimestamp {
         self.saturating_sub(UTCTimestamp::from_micros(rhs))
     }
@@ -304,6 +646,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` subtraction with milliseconds. Computes `self + other`, returning [`UTCTimestamp::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub_millis(self, rhs: u64) -> UTCTimestamp {
         self.saturating_sub(UTCTimestamp::from_millis(rhs))
     }
@@ -311,13 +654,144 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` subtraction with seconds. Computes `self + other`, returning [`UTCTimestamp::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub_secs(self, rhs: u64) -> UTCTimestamp {
         self.saturating_sub(UTCTimestamp::from_secs(rhs))
     }
 
+    /// Overflowing `UTCTimestamp` subtraction. Computes `self - other`, returning a tuple of the
+    /// difference (saturated at [`UTCTimestamp::ZERO`]) and the magnitude of any underflow.
+    ///
+    /// The underflow is [`Duration::ZERO`] if `other` does not exceed `self`.
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_sub(self, rhs: UTCTimestamp) -> (UTCTimestamp, Duration) {
+        match self.0.checked_sub(rhs.0) {
+            Some(duration) => (UTCTimestamp(duration), Duration::ZERO),
+            None => (UTCTimestamp::ZERO, rhs.0.saturating_sub(self.0)),
+        }
+    }
+
+    /// Checked `UTCTimestamp` addition with signed nanoseconds. Computes `self + delta`,
+    /// returning [`None`] if the result would be negative or exceed [`UTCTimestamp::MAX`].
+    ///
+    /// Negative `delta` are subtracted, eg. to apply a signed clock correction.
+    #[inline]
+    #[must_use]
+    pub const fn checked_add_signed_nanos(self, delta: i128) -> Option<UTCTimestamp> {
+        // all timestamps are representable as i128 nanoseconds
+        let nanos = match (self.as_nanos() as i128).checked_add(delta) {
+            Some(nanos) if nanos >= 0 => nanos,
+            _ => return None,
+        };
+        match Self::try_from_nanos_u128(nanos as u128) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the `Duration` elapsed from `earlier` to `self`, or [`None`]
+    /// if `earlier` is later than `self`.
+    #[inline]
+    #[must_use]
+    pub const fn checked_duration_since(self, earlier: UTCTimestamp) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    /// Returns the `Duration` elapsed from `earlier` to `self`, or [`Duration::ZERO`]
+    /// if `earlier` is later than `self`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_duration_since(self, earlier: UTCTimestamp) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns whether `self` and `other` are within `tolerance` (inclusive) of each other,
+    /// eg. when comparing values derived from the system time.
+    #[inline]
+    #[must_use]
+    pub const fn approx_eq(&self, other: UTCTimestamp, tolerance: Duration) -> bool {
+        let diff = match self.0.checked_sub(other.0) {
+            Some(diff) => diff,
+            None => other.0.saturating_sub(self.0),
+        };
+        diff.as_nanos() <= tolerance.as_nanos()
+    }
+
+    /// Linearly interpolate between timestamps `a` and `b`. Computes `a + (b - a) * t`,
+    /// with `t` clamped to `[0.0, 1.0]` (`NaN` is treated as `0.0`).
+    ///
+    /// Timestamp `b` may precede `a`, interpolating backwards from `a`.
+    /// Interpolation is computed on integer nanoseconds, with `t` in 64-bit fixed point,
+    /// avoiding floating point precision loss over large spans.
+    /// The result is rounded towards `a`.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimestamp;
+    ///
+    /// let a = UTCTimestamp::from_secs(100);
+    /// let b = UTCTimestamp::from_secs(200);
+    /// assert_eq!(UTCTimestamp::lerp(a, b, 0.25), UTCTimestamp::from_secs(125));
+    /// assert_eq!(UTCTimestamp::lerp(b, a, 0.25), UTCTimestamp::from_secs(175));
+    /// ```
+    #[must_use]
+    pub fn lerp(a: UTCTimestamp, b: UTCTimestamp, t: f64) -> UTCTimestamp {
+        const FRAC_BITS: u32 = 64;
+        // `f64::clamp` propagates NaN
+        let t = if t > 0.0 { t.min(1.0) } else { 0.0 };
+        // scaling by a power of 2 is exact. The product is an integer for any `t >= 2^-12`
+        // (an f64 ulp of at least 2^-64), smaller `t` truncate bits below 2^-64
+        let t_fixed = (t * (1u128 << FRAC_BITS) as f64) as u128;
+        let (span, forwards) = match b.0.checked_sub(a.0) {
+            Some(span) => (span.as_nanos(), true),
+            None => ((a.0 - b.0).as_nanos(), false),
+        };
+        // split the span to avoid u128 overflow
+        let high = (span >> FRAC_BITS) * t_fixed;
+        let low = ((span & ((1 << FRAC_BITS) - 1)) * t_fixed) >> FRAC_BITS;
+        let offset = high + low;
+        // offset does not exceed the span, so is representable and within range
+        let offset = Duration::new(
+            (offset / NANOS_PER_SECOND as u128) as u64,
+            (offset % NANOS_PER_SECOND as u128) as u32,
+        );
+        if forwards {
+            UTCTimestamp(a.0 + offset)
+        } else {
+            UTCTimestamp(a.0 - offset)
+        }
+    }
+
+    /// Returns the whole seconds elapsed from the reference `epoch` to `self`, or [`None`]
+    /// if `epoch` is later than `self`.
+    ///
+    /// Useful for expressing timestamps relative to epochs other than the Unix epoch.
+    #[inline]
+    #[must_use]
+    pub const fn seconds_since(self, epoch: UTCTimestamp) -> Option<u64> {
+        match self.checked_duration_since(epoch) {
+            Some(duration) => Some(duration.as_secs()),
+            None => None,
+        }
+    }
+
+    /// Returns the absolute difference between `self` and `other` as a `Duration`,
+    /// regardless of which timestamp is later.
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff(self, other: UTCTimestamp) -> Duration {
+        match self.checked_duration_since(other) {
+            Some(duration) => duration,
+            None => other.0.saturating_sub(self.0),
+        }
+    }
+
     /// Checked `UTCTimestamp` multiplication. Computes `self * other`, returning
     /// [`None`] if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_mul(self, rhs: u32) -> Option<UTCTimestamp> {
         match self.0.checked_mul(rhs) {
             Some(duration) => Some(UTCTimestamp(duration)),
@@ -328,6 +802,7 @@ impl UTCTimestamp {
     /// Saturating `UTCTimestamp` multiplication. Computes `self * other`, returning
     /// [`UTCTimestamp::MAX`] if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_mul(self, rhs: u32) -> UTCTimestamp {
         match self.checked_mul(rhs) {
             Some(res) => res,
@@ -335,9 +810,48 @@ impl UTCTimestamp {
         }
     }
 
+    /// Checked `UTCTimestamp` multiplication with `u64`. Computes `self * other`, returning
+    /// [`None`] if overflow occurred.
+    #[must_use]
+    pub const fn checked_mul_u64(self, rhs: u64) -> Option<UTCTimestamp> {
+        let nanos = match self.as_nanos().checked_mul(rhs as u128) {
+            Some(nanos) => nanos,
+            None => return None,
+        };
+        match Self::try_from_nanos_u128(nanos) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
+    /// Saturating `UTCTimestamp` multiplication with `u64`. Computes `self * other`, returning
+    /// [`UTCTimestamp::MAX`] if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_mul_u64(self, rhs: u64) -> UTCTimestamp {
+        match self.checked_mul_u64(rhs) {
+            Some(res) => res,
+            None => UTCTimestamp::MAX,
+        }
+    }
+
+    /// Checked `UTCTimestamp` division with `u64`. Computes `self / other`, returning [`None`]
+    /// if `other` == 0.
+    #[must_use]
+    pub const fn checked_div_u64(self, rhs: u64) -> Option<UTCTimestamp> {
+        if rhs == 0 {
+            return None;
+        }
+        match Self::try_from_nanos_u128(self.as_nanos() / rhs as u128) {
+            Ok(res) => Some(res),
+            Err(_) => None,
+        }
+    }
+
     /// Checked `UTCTimestamp` division. Computes `self / other`, returning [`None`]
     /// if `other` == 0.
     #[inline]
+    #[must_use]
     pub const fn checked_div(self, rhs: u32) -> Option<UTCTimestamp> {
         match self.0.checked_div(rhs) {
             Some(duration) => Some(UTCTimestamp(duration)),
@@ -352,6 +866,59 @@ impl From<Duration> for UTCTimestamp {
     }
 }
 
+/// Borrow the internal Duration since the Unix Epoch,
+/// for APIs generic over `AsRef<Duration>`.
+///
+/// `Deref` is intentionally not implemented, to keep `Duration` methods
+/// out of the timestamp API.
+impl AsRef<Duration> for UTCTimestamp {
+    fn as_ref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+/// Timestamps hash and compare identically to their internal Duration.
+impl Borrow<Duration> for UTCTimestamp {
+    fn borrow(&self) -> &Duration {
+        &self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for UTCTimestamp {
+    type Error = SystemTimeError;
+
+    /// Try to create a UTC Timestamp from a system time, erroring if it precedes the Unix Epoch.
+    fn try_from(system_time: SystemTime) -> Result<Self, Self::Error> {
+        let duration = system_time.duration_since(SystemTime::UNIX_EPOCH)?;
+        Ok(Self(duration))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<UTCTimestamp> for SystemTime {
+    /// Create a system time from a UTC Timestamp.
+    ///
+    /// Panics if the timestamp cannot be represented by the platform's `SystemTime`.
+    fn from(timestamp: UTCTimestamp) -> Self {
+        SystemTime::UNIX_EPOCH + timestamp.0
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for UTCTimestamp {
+    /// Generates timestamps within `[UTCTimestamp::ZERO, UTCTimestamp::MAX]`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let secs = u.int_in_range(0..=Self::MAX.as_secs())?;
+        let subsec_ns = u.int_in_range(0..=(NANOS_PER_SECOND - 1) as u32)?;
+        Ok(Self::from_duration(Duration::new(secs, subsec_ns)).min(Self::MAX))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(u64::size_hint(depth), u32::size_hint(depth))
+    }
+}
+
 impl From<UTCDay> for UTCTimestamp {
     #[inline]
     fn from(day: UTCDay) -> Self {
@@ -359,6 +926,32 @@ impl From<UTCDay> for UTCTimestamp {
     }
 }
 
+impl Display for UTCTimestamp {
+    /// Formats the timestamp as an ISO datetime (see [UTCDatetime]) at millisecond precision,
+    /// or at the formatter precision if specified (eg. `{:.9}`).
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let datetime = UTCDatetime::from(*self);
+        match f.precision() {
+            Some(_) => Display::fmt(&datetime, f),
+            None => write!(f, "{datetime:.3}"),
+        }
+    }
+}
+
+impl LowerHex for UTCTimestamp {
+    /// Formats the nanoseconds since the epoch as lowercase hexadecimal
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        LowerHex::fmt(&self.as_nanos(), f)
+    }
+}
+
+impl UpperHex for UTCTimestamp {
+    /// Formats the nanoseconds since the epoch as uppercase hexadecimal
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        UpperHex::fmt(&self.as_nanos(), f)
+    }
+}
+
 impl Add for UTCTimestamp {
     type Output = UTCTimestamp;
 
@@ -377,6 +970,24 @@ impl Add<Duration> for UTCTimestamp {
     }
 }
 
+impl Add<UTCDay> for UTCTimestamp {
+    type Output = UTCTimestamp;
+
+    fn add(self, rhs: UTCDay) -> Self::Output {
+        self.checked_add_days(rhs)
+            .expect("overflow when adding timestamps")
+    }
+}
+
+impl Add<UTCTimeOfDay> for UTCTimestamp {
+    type Output = UTCTimestamp;
+
+    fn add(self, rhs: UTCTimeOfDay) -> Self::Output {
+        self.checked_add_tod(rhs)
+            .expect("overflow when adding timestamps")
+    }
+}
+
 impl AddAssign for UTCTimestamp {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs
@@ -389,6 +1000,18 @@ impl AddAssign<Duration> for UTCTimestamp {
     }
 }
 
+impl AddAssign<UTCDay> for UTCTimestamp {
+    fn add_assign(&mut self, rhs: UTCDay) {
+        *self = *self + rhs
+    }
+}
+
+impl AddAssign<UTCTimeOfDay> for UTCTimestamp {
+    fn add_assign(&mut self, rhs: UTCTimeOfDay) {
+        *self = *self + rhs
+    }
+}
+
 impl Sub for UTCTimestamp {
     type Output = UTCTimestamp;
 
@@ -457,6 +1080,31 @@ impl DivAssign<u32> for UTCTimestamp {
     }
 }
 
+/// Error type for UTCTimestamp methods
+#[derive(Debug, Clone)]
+pub enum UTCTimestampError {
+    /// Error raised due to a Windows FILETIME preceding the Unix Epoch
+    FiletimeBeforeEpoch(u64),
+    /// Error raised due to nanoseconds exceeding the maximum UTC Timestamp
+    ExcessNanos(u128),
+    /// Error raised due to subsecond nanoseconds exceeding one second
+    ExcessSubsecNanos(u32),
+}
+
+impl Display for UTCTimestampError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FiletimeBeforeEpoch(ft) => write!(f, "FILETIME ({ft}) precedes the Unix Epoch"),
+            Self::ExcessNanos(n) => write!(f, "nanoseconds ({n}) exceeding maximum UTC Timestamp"),
+            Self::ExcessSubsecNanos(n) => {
+                write!(f, "subsecond nanoseconds ({n}) exceeding one second")
+            }
+        }
+    }
+}
+
+impl Error for UTCTimestampError {}
+
 /// Common methods for creating and converting between UTC structures.
 ///
 /// ## Examples
@@ -523,6 +1171,7 @@ where
 {
     /// Create from a duration measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn from_duration(duration: Duration) -> Self {
         let timestamp = UTCTimestamp(duration);
         Self::from_timestamp(timestamp)
@@ -530,12 +1179,14 @@ where
 
     /// Convert to a duration measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn as_duration(&self) -> Duration {
         self.as_timestamp().as_duration()
     }
 
     /// Create from seconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn from_secs(secs: u64) -> Self {
         let timestamp = UTCTimestamp::from_secs(secs);
         Self::from_timestamp(timestamp)
@@ -543,12 +1194,14 @@ where
 
     /// Convert to seconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn as_secs(&self) -> u64 {
         self.as_timestamp().as_secs()
     }
 
     /// Create from milliseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn from_millis(millis: u64) -> Self {
         let timestamp = UTCTimestamp::from_millis(millis);
         Self::from_timestamp(timestamp)
@@ -556,12 +1209,14 @@ where
 
     /// Convert to milliseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn as_millis(&self) -> u128 {
         self.as_timestamp().as_millis()
     }
 
     /// Create from microseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn from_micros(micros: u64) -> Self {
         let timestamp = UTCTimestamp::from_micros(micros);
         Self::from_timestamp(timestamp)
@@ -569,12 +1224,14 @@ where
 
     /// Convert to microseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn as_micros(&self) -> u128 {
         self.as_timestamp().as_micros()
     }
 
     /// Create from nanoseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn from_nanos(nanos: u64) -> Self {
         let timestamp = UTCTimestamp::from_nanos(nanos);
         Self::from_timestamp(timestamp)
@@ -582,6 +1239,7 @@ where
 
     /// Convert to nanoseconds measured from the Unix Epoch.
     #[inline]
+    #[must_use]
     fn as_nanos(&self) -> u128 {
         self.as_timestamp().as_nanos()
     }
@@ -594,8 +1252,10 @@ where
     }
 
     /// Create from a UTC timestamp.
+    #[must_use]
     fn from_timestamp(timestamp: UTCTimestamp) -> Self;
     /// Convert to a UTC timestamp.
+    #[must_use]
     fn as_timestamp(&self) -> UTCTimestamp;
 }
 
@@ -624,6 +1284,11 @@ where
 /// Unchecked methods are provided for use in hot paths requiring high levels of optimisation.
 /// These methods assume valid input.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct UTCDay(u64);
 
@@ -643,6 +1308,7 @@ impl UTCDay {
     /// ## Safety
     /// Unsafe if the user passes an unsupported count of UTC days, exceeding `UTCDay::MAX`.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_u64_unchecked(u: u64) -> Self {
         Self(u)
     }
@@ -656,30 +1322,110 @@ impl UTCDay {
         Ok(day)
     }
 
+    /// Get a stable 64-bit FNV-1a hash of the UTC Day.
+    ///
+    /// Unlike the derived `Hash`, the output is stable across platforms and Rust versions,
+    /// computed over the days since the Unix Epoch as little-endian `u64` bytes.
+    #[inline]
+    #[must_use]
+    pub const fn fnv1a_hash(&self) -> u64 {
+        fnv1a_64(&self.0.to_le_bytes())
+    }
+
+    /// Get the little-endian byte representation of the UTC Day,
+    /// as the days since the Unix Epoch as a little-endian `u64`.
+    #[inline]
+    #[must_use]
+    pub const fn to_le_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Get the big-endian byte representation of the UTC Day,
+    /// as the days since the Unix Epoch as a big-endian `u64`.
+    #[inline]
+    #[must_use]
+    pub const fn to_be_bytes(&self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Try create UTC Day from its little-endian byte representation,
+    /// see [UTCDay::to_le_bytes].
+    pub fn try_from_le_bytes(bytes: [u8; 8]) -> Result<Self, UTCDayErrOutOfRange> {
+        Self::try_from_u64(u64::from_le_bytes(bytes))
+    }
+
+    /// Try create UTC Day from its big-endian byte representation,
+    /// see [UTCDay::to_be_bytes].
+    pub fn try_from_be_bytes(bytes: [u8; 8]) -> Result<Self, UTCDayErrOutOfRange> {
+        Self::try_from_u64(u64::from_be_bytes(bytes))
+    }
+
     /// UTC Day as internal integer
     #[inline]
+    #[must_use]
     pub const fn as_u64(&self) -> u64 {
         self.0
     }
 
     /// Consume UTC Day to internal integer
     #[inline]
+    #[must_use]
     pub const fn to_u64(self) -> u64 {
         self.0
     }
 
+    /// Create UTC Day from a packed `u32` count of days since the Unix Epoch.
+    ///
+    /// The `u32` form halves storage at the cost of range, supporting dates
+    /// from `1970-01-01` up to `+11761191-01-20` (day `u32::MAX`).
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(u: u32) -> Self {
+        Self(u as u64)
+    }
+
+    /// Consume UTC Day to a packed `u32` count of days since the Unix Epoch.
+    ///
+    /// Returns `None` if the day exceeds `u32::MAX` (after `+11761191-01-20`),
+    /// see [UTCDay::from_u32].
+    #[inline]
+    #[must_use]
+    pub const fn to_u32(self) -> Option<u32> {
+        if self.0 > u32::MAX as u64 {
+            return None;
+        }
+        Some(self.0 as u32)
+    }
+
     /// Calculate and return the day of the week in numerical form
     /// `[0, 6]` represents `[Sun, Sat]`
     ///
     /// Reference:
     /// <http://howardhinnant.github.io/date_algorithms.html#weekday_from_days>
-    pub fn as_weekday(&self) -> u8 {
-        ((self.0 + 4) % 7) as u8
+    #[must_use]
+    pub const fn as_weekday(&self) -> u8 {
+        weekday_of_day(self.0)
+    }
+
+    /// Calculate and return the ISO 8601 day of the week in numerical form
+    /// `[1, 7]` represents `[Mon, Sun]`
+    #[inline]
+    #[must_use]
+    pub const fn as_iso_weekday(&self) -> u8 {
+        (((self.0 + 3) % 7) + 1) as u8
+    }
+
+    /// Calculate and return the day of the week
+    #[inline]
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        Weekday::of_day(self.0)
     }
 
     /// Checked `UTCDay` addition. Computes `self + other`, returning [`None`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub fn checked_add(self, rhs: UTCDay) -> Option<UTCDay> {
         self.0
             .checked_add(rhs.0)
@@ -689,6 +1435,7 @@ impl UTCDay {
     /// Checked `UTCDay` addition with `u64`. Computes `self + other`, returning [`None`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub fn checked_add_u64(self, rhs: u64) -> Option<UTCDay> {
         self.0.checked_add(rhs).map(|u| UTCDay(u).min(UTCDay::MAX))
     }
@@ -696,6 +1443,7 @@ impl UTCDay {
     /// Saturating `UTCDay` addition. Computes `self + other`, returning [`UTCDay::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub fn saturating_add(self, rhs: UTCDay) -> UTCDay {
         match self.checked_add(rhs) {
             Some(res) => res,
@@ -703,9 +1451,21 @@ impl UTCDay {
         }
     }
 
+    /// Saturating `UTCDay` addition, reporting saturation. Computes `self + other`,
+    /// returning [`UTCDay::MAX`] and [`Saturation::Max`] if the result would exceed it.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add_reporting(self, rhs: UTCDay) -> (UTCDay, Saturation) {
+        match self.0.checked_add(rhs.0) {
+            Some(u) if u <= UTCDay::MAX.0 => (UTCDay(u), Saturation::None),
+            _ => (UTCDay::MAX, Saturation::Max),
+        }
+    }
+
     /// Saturating `UTCDay` addition with `u64`. Computes `self + other`, returning [`UTCDay::MAX`]
     /// if overflow occurred.
     #[inline]
+    #[must_use]
     pub fn saturating_add_u64(self, rhs: u64) -> UTCDay {
         match self.checked_add_u64(rhs) {
             Some(res) => res,
@@ -713,9 +1473,33 @@ impl UTCDay {
         }
     }
 
+    /// Checked `UTCDay` addition with a signed `i64` delta. Computes `self + delta`, returning [`None`]
+    /// if the result would be negative or would exceed [`UTCDay::MAX`].
+    #[inline]
+    #[must_use]
+    pub const fn checked_add_signed(self, delta: i64) -> Option<UTCDay> {
+        match self.0.checked_add_signed(delta) {
+            Some(u) if u <= UTCDay::MAX.0 => Some(UTCDay(u)),
+            _ => None,
+        }
+    }
+
+    /// Saturating `UTCDay` addition with a signed `i64` delta. Computes `self + delta`, returning
+    /// [`UTCDay::ZERO`] if the result would be negative, or [`UTCDay::MAX`] if the result would exceed it.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add_signed(self, delta: i64) -> UTCDay {
+        match self.checked_add_signed(delta) {
+            Some(res) => res,
+            None if delta < 0 => UTCDay::ZERO,
+            None => UTCDay::MAX,
+        }
+    }
+
     /// Checked `UTCDay` subtraction. Computes `self - other`, returning [`None`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_sub(self, rhs: UTCDay) -> Option<UTCDay> {
         match self.0.checked_sub(rhs.0) {
             Some(u) => Some(UTCDay(u)),
@@ -726,6 +1510,7 @@ impl UTCDay {
     /// Checked `UTCDay` subtraction with `u64`. Computes `self - other`, returning [`None`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn checked_sub_u64(self, rhs: u64) -> Option<UTCDay> {
         match self.0.checked_sub(rhs) {
             Some(u) => Some(UTCDay(u)),
@@ -736,6 +1521,7 @@ impl UTCDay {
     /// Saturating `UTCDay` subtraction. Computes `self - other`, returning [`UTCDay::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub(self, rhs: UTCDay) -> UTCDay {
         match self.checked_sub(rhs) {
             Some(res) => res,
@@ -743,9 +1529,21 @@ impl UTCDay {
         }
     }
 
+    /// Saturating `UTCDay` subtraction, reporting saturation. Computes `self - other`,
+    /// returning [`UTCDay::ZERO`] and [`Saturation::Min`] if the result would be negative.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub_reporting(self, rhs: UTCDay) -> (UTCDay, Saturation) {
+        match self.checked_sub(rhs) {
+            Some(res) => (res, Saturation::None),
+            None => (UTCDay::ZERO, Saturation::Min),
+        }
+    }
+
     /// Saturating `UTCDay` subtraction with `u64`. Computes `self - other`, returning [`UTCDay::ZERO`]
     /// if the result would be negative or if overflow occurred.
     #[inline]
+    #[must_use]
     pub const fn saturating_sub_u64(self, rhs: u64) -> UTCDay {
         match self.checked_sub_u64(rhs) {
             Some(res) => res,
@@ -756,6 +1554,7 @@ impl UTCDay {
     /// Checked `UTCDay` multiplication. Computes `self * other`, returning
     /// [`None`] if overflow occurred.
     #[inline]
+    #[must_use]
     pub fn checked_mul(self, rhs: u64) -> Option<UTCDay> {
         self.0.checked_mul(rhs).map(|u| UTCDay(u).min(UTCDay::MAX))
     }
@@ -763,6 +1562,7 @@ impl UTCDay {
     /// Saturating `UTCDay` multiplication. Computes `self * other`, returning
     /// [`UTCDay::MAX`] if overflow occurred.
     #[inline]
+    #[must_use]
     pub fn saturating_mul(self, rhs: u64) -> UTCDay {
         match self.checked_mul(rhs) {
             Some(res) => res,
@@ -773,6 +1573,7 @@ impl UTCDay {
     /// Checked `UTCDay` division. Computes `self / other`, returning [`None`]
     /// if `other` == 0.
     #[inline]
+    #[must_use]
     pub const fn checked_div(self, rhs: u64) -> Option<UTCDay> {
         match self.0.checked_div(rhs) {
             Some(u) => Some(UTCDay(u)),
@@ -942,6 +1743,23 @@ impl DivAssign<u64> for UTCDay {
     }
 }
 
+impl Sum for UTCDay {
+    /// Sums day counts, saturating at [UTCDay::MAX].
+    ///
+    /// `Product` is intentionally not implemented, as multiplying days is not meaningful.
+    /// Likewise dates ([UTCDate](crate::date::UTCDate)) implement neither.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(UTCDay::ZERO, UTCDay::saturating_add)
+    }
+}
+
+impl<'a> Sum<&'a UTCDay> for UTCDay {
+    /// Sums day counts, saturating at [UTCDay::MAX].
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl TryFrom<u64> for UTCDay {
     type Error = UTCDayErrOutOfRange;
 
@@ -964,13 +1782,158 @@ impl From<UTCTimestamp> for UTCDay {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for UTCDay {
+    /// Generates days within `[UTCDay::ZERO, UTCDay::MAX]`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UTCDay(u.int_in_range(0..=Self::MAX.0)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+impl LowerHex for UTCDay {
+    /// Formats the days since the epoch as lowercase hexadecimal
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl UpperHex for UTCDay {
+    /// Formats the days since the epoch as uppercase hexadecimal
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
+/// Saturation outcome of saturating arithmetic.
+///
+/// Reports whether the result was clamped, and to which bound.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Saturation {
+    /// The result was exact, no saturation occurred
+    #[default]
+    None,
+    /// The result saturated at the minimum value
+    Min,
+    /// The result saturated at the maximum value
+    Max,
+}
+
+/// Day of the week.
+///
+/// Numbered `[0, 6]` from Sunday to Saturday, consistent with [UTCDay::as_weekday].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Sunday (0)
+    Sunday = 0,
+    /// Monday (1)
+    Monday = 1,
+    /// Tuesday (2)
+    Tuesday = 2,
+    /// Wednesday (3)
+    Wednesday = 3,
+    /// Thursday (4)
+    Thursday = 4,
+    /// Friday (5)
+    Friday = 5,
+    /// Saturday (6)
+    Saturday = 6,
+}
+
+impl Weekday {
+    /// All weekdays, in numerical order from Sunday
+    pub const ALL: [Self; 7] = [
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+    ];
+
+    /// Weekday in numerical form, `[0, 6]` represents `[Sun, Sat]`
+    #[inline]
+    #[must_use]
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Number of days from this weekday until the next occurrence of `other`, in the range `[0, 6]`
+    ///
+    /// Returns `0` if the weekdays are equal.
+    #[inline]
+    #[must_use]
+    pub const fn days_until(&self, other: Weekday) -> u8 {
+        (other.as_u8() + 7 - self.as_u8()) % 7
+    }
+
+    /// Weekday of the given number of days since the Unix Epoch,
+    /// see [weekday_of_day] for the numerical form.
+    #[inline]
+    #[must_use]
+    pub const fn of_day(day: u64) -> Self {
+        Self::ALL[weekday_of_day(day) as usize]
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Calculate the day of the week of the given number of days since the Unix Epoch,
+/// in numerical form. `[0, 6]` represents `[Sun, Sat]`
+///
+/// Equivalent to [UTCDay::as_weekday], without requiring a [UTCDay],
+/// eg. for building const tables directly from integers.
+/// All `u64` day counts are supported.
+///
+/// Reference:
+/// <http://howardhinnant.github.io/date_algorithms.html#weekday_from_days>
+///
+/// ## Examples
+#[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "std", doc = "```rust")]
+/// use utc_dt::time::{weekday_of_day, Weekday};
+///
+/// const WEEKDAYS: [u8; 3] = [weekday_of_day(0), weekday_of_day(1), weekday_of_day(19523)];
+/// assert_eq!(WEEKDAYS, [4, 5, 4]); // thursday, friday, thursday
+/// assert_eq!(Weekday::of_day(19523), Weekday::Thursday);
+/// ```
+#[inline]
+#[must_use]
+pub const fn weekday_of_day(day: u64) -> u8 {
+    // reduce first, to avoid overflow of the epoch offset
+    ((day % 7 + 4) % 7) as u8
+}
+
 /// UTC Time of Day
 ///
 /// A time of day measurement with nanosecond resolution.
 ///
+/// Adding or subtracting a `Duration` via operators **wraps around midnight**,
+/// discarding any whole days carried, as the time of day is cyclic (eg. `23:00 + 2h = 01:00`).
+///
 /// ## Examples
 #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
 #[cfg_attr(feature = "std", doc = "```rust")]
+/// use core::time::Duration;
 /// use utc_dt::time::UTCTimeOfDay;
 ///
 /// // UTC Time of Day from a time measurement (for secs, millis, micros, nanos)
@@ -996,26 +1959,41 @@ impl From<UTCTimestamp> for UTCDay {
 /// let _bytes_written = utc_tod.write_iso_tod(&mut buf, PRECISION_MICROS).unwrap();
 /// let iso_tod_str = core::str::from_utf8(&buf).unwrap();
 /// assert_eq!(iso_tod_str, "T10:18:08.903000Z");
+/// // Add or subtract durations, wrapping around midnight
+/// let late = UTCTimeOfDay::try_from_hhmmss(23, 0, 0, 0).unwrap();
+/// let early = late + Duration::from_secs(2 * 60 * 60);
+/// assert_eq!(early, UTCTimeOfDay::try_from_hhmmss(1, 0, 0, 0).unwrap());
+/// assert_eq!(early - Duration::from_secs(2 * 60 * 60), late);
 /// ```
 ///
 /// ## Safety
 /// Unchecked methods are provided for use in hot paths requiring high levels of optimisation.
 /// These methods assume valid input.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct UTCTimeOfDay(u64);
 
 impl Display for UTCTimeOfDay {
+    /// Formats the ISO time of day at nanosecond precision,
+    /// or at the formatter precision if specified (eg. `{:.3}`), truncated to at most 9 decimal places.
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let (hrs, mins, secs) = self.as_hhmmss();
-        write!(
-            f,
-            "T{:02}:{:02}:{:02}.{:09}Z",
-            hrs,
-            mins,
-            secs,
-            self.as_subsec_ns()
-        )
+        write!(f, "T{:02}:{:02}:{:02}", hrs, mins, secs)?;
+        let precision = f
+            .precision()
+            .unwrap_or(Self::MAX_ISO_TOD_PRECISION)
+            .min(Self::MAX_ISO_TOD_PRECISION);
+        if precision > 0 {
+            let subsec =
+                self.as_subsec_ns() / 10u32.pow((Self::MAX_ISO_TOD_PRECISION - precision) as u32);
+            write!(f, ".{:0width$}", subsec, width = precision)?;
+        }
+        write!(f, "Z")
     }
 }
 
@@ -1034,13 +2012,22 @@ impl UTCTimeOfDay {
     /// The maximum supported subsecond precision of an ISO time
     pub const MAX_ISO_TOD_PRECISION: usize = 9;
 
+    /// The maximum length of an ISO time (in UTF8 characters)
+    pub const MAX_ISO_TOD_LEN: usize = Self::iso_tod_len(Self::MAX_ISO_TOD_PRECISION);
+
     /// Unchecked method to create time of day from nanoseconds
     ///
     /// ### Safety
     /// Unsafe if the user passes an invalid time-of-day nanoseconds component (exceeding NANOS_PER_DAY).
-    /// Invalid inputs are not checked and may cause a panic in other methods.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_nanos_unchecked(nanos: u64) -> Self {
+        debug_assert!(
+            nanos < NANOS_PER_DAY,
+            "unchecked UTCTimeOfDay exceeds a day"
+        );
         Self(nanos)
     }
 
@@ -1048,9 +2035,15 @@ impl UTCTimeOfDay {
     ///
     /// ### Safety
     /// Unsafe if the user passes an invalid time-of-day microsecond component (exceeding MICROS_PER_DAY).
-    /// Invalid inputs are not checked and may cause a panic in other methods.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_micros_unchecked(micros: u64) -> Self {
+        debug_assert!(
+            micros < MICROS_PER_DAY,
+            "unchecked UTCTimeOfDay exceeds a day"
+        );
         Self(micros * NANOS_PER_MICRO)
     }
 
@@ -1058,9 +2051,15 @@ impl UTCTimeOfDay {
     ///
     /// ### Safety
     /// Unsafe if the user passes an invalid time-of-day millisecond component (exceeding MILLIS_PER_DAY).
-    /// Invalid inputs are not checked and may cause a panic in other methods.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_millis_unchecked(millis: u32) -> Self {
+        debug_assert!(
+            (millis as u64) < MILLIS_PER_DAY,
+            "unchecked UTCTimeOfDay exceeds a day"
+        );
         Self((millis as u64) * NANOS_PER_MILLI)
     }
 
@@ -1068,9 +2067,15 @@ impl UTCTimeOfDay {
     ///
     /// ### Safety
     /// Unsafe if the user passes an invalid time-of-day seconds component (exceeding SECONDS_PER_DAY).
-    /// Invalid inputs are not checked and may cause a panic in other methods.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_secs_unchecked(secs: u32) -> Self {
+        debug_assert!(
+            (secs as u64) < SECONDS_PER_DAY,
+            "unchecked UTCTimeOfDay exceeds a day"
+        );
         Self((secs as u64) * NANOS_PER_SECOND)
     }
 
@@ -1085,50 +2090,87 @@ impl UTCTimeOfDay {
     ///
     /// # Safety
     /// Unsafe if the user passes a measure of time exceeding a day.
-    /// Invalid inputs are not checked and may cause a panic in other methods.
+    /// Invalid inputs are not checked in release builds and may cause a panic in other methods.
+    /// In debug builds, invalid inputs panic immediately.
     #[inline]
+    #[must_use]
     pub const unsafe fn from_hhmmss_unchecked(hrs: u8, mins: u8, secs: u8, subsec_ns: u32) -> Self {
-        Self(Self::_ns_from_hhmmss(hrs, mins, secs, subsec_ns))
+        Self::from_nanos_unchecked(Self::_ns_from_hhmmss(hrs, mins, secs, subsec_ns))
+    }
+
+    /// Create a normalized UTC time of day from hours, minutes, seconds and subsecond (nanosecond)
+    /// components, returning the number of whole days carried and the remaining time of day.
+    ///
+    /// Components are treated additively, as per [UTCTimeOfDay::try_from_hhmmss],
+    /// but times exceeding a day carry into the returned days rather than raising an error.
+    /// A non-zero carry indicates the components exceeded the length of a day.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimeOfDay;
+    ///
+    /// let (carry_days, tod) = UTCTimeOfDay::from_hhmmss_normalized(25, 0, 0, 0);
+    /// assert_eq!(carry_days, 1);
+    /// assert_eq!(tod, UTCTimeOfDay::try_from_hhmmss(1, 0, 0, 0).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_hhmmss_normalized(
+        hrs: u8,
+        mins: u8,
+        secs: u8,
+        subsec_ns: u32,
+    ) -> (u64, Self) {
+        let nanos = Self::_ns_from_hhmmss(hrs, mins, secs, subsec_ns);
+        // SAFETY: remainder is within NANOS_PER_DAY
+        let tod = unsafe { Self::from_nanos_unchecked(nanos % NANOS_PER_DAY) };
+        (nanos / NANOS_PER_DAY, tod)
     }
 
     /// Try to create UTC time of day from nanoseconds
     pub fn try_from_nanos(nanos: u64) -> Result<Self, UTCTimeOfDayError> {
-        // SAFETY: we immediately check that nanos was within NANOS_PER_DAY (tod does not exceed UTCTimeOfDay::MAX)
-        let tod = unsafe { Self::from_nanos_unchecked(nanos) };
-        if tod > Self::MAX {
+        if nanos >= NANOS_PER_DAY {
             return Err(UTCTimeOfDayError::ExcessNanos(nanos));
         }
-        Ok(tod)
+        // SAFETY: we have checked that nanos is within NANOS_PER_DAY
+        Ok(unsafe { Self::from_nanos_unchecked(nanos) })
     }
 
     /// Try to create UTC time of day from microseconds
     pub fn try_from_micros(micros: u64) -> Result<Self, UTCTimeOfDayError> {
-        // SAFETY: we immediately check that micros was within MICROS_PER_DAY (tod does not exceed UTCTimeOfDay::MAX)
-        let tod = unsafe { Self::from_micros_unchecked(micros) };
-        if tod > Self::MAX {
+        if micros >= MICROS_PER_DAY {
             return Err(UTCTimeOfDayError::ExcessMicros(micros));
         }
-        Ok(tod)
+        // SAFETY: we have checked that micros is within MICROS_PER_DAY
+        Ok(unsafe { Self::from_micros_unchecked(micros) })
     }
 
     /// Try to create UTC time of day from milliseconds
     pub fn try_from_millis(millis: u32) -> Result<Self, UTCTimeOfDayError> {
-        // SAFETY: we immediately check that millis was within MILLIS_PER_DAY (tod does not exceed UTCTimeOfDay::MAX)
-        let tod = unsafe { Self::from_millis_unchecked(millis) };
-        if tod > Self::MAX {
+        if millis as u64 >= MILLIS_PER_DAY {
             return Err(UTCTimeOfDayError::ExcessMillis(millis));
         }
-        Ok(tod)
+        // SAFETY: we have checked that millis is within MILLIS_PER_DAY
+        Ok(unsafe { Self::from_millis_unchecked(millis) })
     }
 
     /// Try to create UTC time of day from seconds
     pub fn try_from_secs(secs: u32) -> Result<Self, UTCTimeOfDayError> {
-        // SAFETY: we immediately check that secs was within SECONDS_PER_DAY (tod does not exceed UTCTimeOfDay::MAX)
-        let tod = unsafe { Self::from_secs_unchecked(secs) };
-        if tod > Self::MAX {
+        if secs as u64 >= SECONDS_PER_DAY {
             return Err(UTCTimeOfDayError::ExcessSeconds(secs));
         }
-        Ok(tod)
+        // SAFETY: we have checked that secs is within SECONDS_PER_DAY
+        Ok(unsafe { Self::from_secs_unchecked(secs) })
+    }
+
+    /// Try to create UTC time of day from a `Duration` since the start of the day
+    pub fn try_from_duration(duration: Duration) -> Result<Self, UTCTimeOfDayError> {
+        if duration.as_secs() >= SECONDS_PER_DAY {
+            return Err(UTCTimeOfDayError::ExcessDuration(duration));
+        }
+        // SAFETY: we have checked that duration is within a day
+        Ok(unsafe { Self::from_nanos_unchecked(duration.as_nanos() as u64) })
     }
 
     /// Try to create UTC time of day from hours, minutes, seconds and subsecond (nanosecond) components
@@ -1144,32 +2186,71 @@ impl UTCTimeOfDay {
         Self::try_from_nanos(Self::_ns_from_hhmmss(hrs, mins, secs, subsec_ns))
     }
 
+    /// Try to create UTC time of day from a fraction of a day in the range `[0.0, 1.0)`
+    ///
+    /// The fraction is truncated to whole nanoseconds.
+    pub fn from_fraction_of_day(f: f64) -> Result<Self, UTCTimeOfDayError> {
+        if !(0.0..1.0).contains(&f) {
+            return Err(UTCTimeOfDayError::ExcessFraction(f));
+        }
+        Self::try_from_nanos((f * NANOS_PER_DAY as f64) as u64)
+    }
+
+    /// Try to create UTC time of day from fractional seconds since midnight in the range `[0.0, 86400.0)`
+    ///
+    /// The seconds are rounded to the nearest nanosecond, such that [UTCTimeOfDay::as_secs_f64]
+    /// round-trips within floating point tolerance. Values rounding up to midnight of the next day
+    /// are clamped to [UTCTimeOfDay::MAX].
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimeOfDay;
+    ///
+    /// let tod = UTCTimeOfDay::from_secs_f64(37_088.903).unwrap();
+    /// assert_eq!(tod, UTCTimeOfDay::try_from_hhmmss(10, 18, 8, 903_000_000).unwrap());
+    /// assert!(UTCTimeOfDay::from_secs_f64(86_400.0).is_err());
+    /// ```
+    pub fn from_secs_f64(secs: f64) -> Result<Self, UTCTimeOfDayError> {
+        if !(0.0..SECONDS_PER_DAY as f64).contains(&secs) {
+            return Err(UTCTimeOfDayError::ExcessSecondsF64(secs));
+        }
+        // round half up, `f64::round` is unavailable without `std`
+        let nanos = ((secs * NANOS_PER_SECOND as f64) + 0.5) as u64;
+        Self::try_from_nanos(nanos.min(Self::MAX.0))
+    }
+
     /// Consume self into nanoseconds
     #[inline]
+    #[must_use]
     pub const fn to_nanos(self) -> u64 {
         self.0
     }
 
     /// Time of day as nanoseconds
     #[inline]
+    #[must_use]
     pub const fn as_nanos(&self) -> u64 {
         self.0
     }
 
     /// Time of day as microseconds
     #[inline]
+    #[must_use]
     pub const fn as_micros(&self) -> u64 {
         self.0 / NANOS_PER_MICRO
     }
 
     /// Time of day as milliseconds
     #[inline]
+    #[must_use]
     pub const fn as_millis(&self) -> u32 {
         (self.0 / NANOS_PER_MILLI) as u32
     }
 
     /// Time of day as seconds
     #[inline]
+    #[must_use]
     pub const fn as_secs(&self) -> u32 {
         (self.0 / NANOS_PER_SECOND) as u32
     }
@@ -1177,6 +2258,7 @@ impl UTCTimeOfDay {
     /// Time of day as hours, minutes and seconds (hhmmss) components
     ///
     /// Returns tuple `(hrs: u8, mins: u8, secs: u8)`
+    #[must_use]
     pub const fn as_hhmmss(&self) -> (u8, u8, u8) {
         let hrs = (self.0 / NANOS_PER_HOUR) as u8;
         let mins = ((self.0 % NANOS_PER_HOUR) / NANOS_PER_MINUTE) as u8;
@@ -1184,13 +2266,164 @@ impl UTCTimeOfDay {
         (hrs, mins, secs)
     }
 
+    /// Get the time of day as named hours, minutes, seconds and subsecond components
+    #[must_use]
+    pub const fn components(&self) -> TimeComponents {
+        let (hours, minutes, seconds) = self.as_hhmmss();
+        TimeComponents {
+            hours,
+            minutes,
+            seconds,
+            subsec_nanos: self.as_subsec_ns(),
+        }
+    }
+
+    /// Try to create UTC time of day from named components.
+    ///
+    /// Components are validated as per [UTCTimeOfDay::try_from_hhmmss].
+    pub fn try_from_time_components(components: TimeComponents) -> Result<Self, UTCTimeOfDayError> {
+        Self::try_from_hhmmss(
+            components.hours,
+            components.minutes,
+            components.seconds,
+            components.subsec_nanos,
+        )
+    }
+
     /// Return subsecond component of time of day (in nanoseconds)
     #[inline]
+    #[must_use]
     pub const fn as_subsec_ns(&self) -> u32 {
         (self.0 % NANOS_PER_SECOND) as u32
     }
 
+    /// Time of day as fractional seconds since midnight in the range `[0.0, 86400.0)`
+    #[inline]
+    #[must_use]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.0 as f64 / NANOS_PER_SECOND as f64
+    }
+
+    /// Time of day as a fraction of a day in the range `[0.0, 1.0)`
+    #[inline]
+    #[must_use]
+    pub fn as_fraction_of_day(&self) -> f64 {
+        self.0 as f64 / NANOS_PER_DAY as f64
+    }
+
+    /// Check whether the time of day falls within the half-open window `[start, end)`.
+    ///
+    /// If `start > end`, the window wraps around midnight (eg. overnight `22:00` to `06:00`),
+    /// containing times from `start` until the end of the day and from midnight until `end`.
+    /// If `start == end`, the window is empty.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimeOfDay;
+    ///
+    /// let tod = UTCTimeOfDay::try_from_hhmmss(23, 30, 0, 0).unwrap();
+    /// let ten_pm = UTCTimeOfDay::try_from_hhmmss(22, 0, 0, 0).unwrap();
+    /// let six_am = UTCTimeOfDay::try_from_hhmmss(6, 0, 0, 0).unwrap();
+    /// assert!(tod.is_between(ten_pm, six_am));
+    /// assert!(!tod.is_between(six_am, ten_pm));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_between(&self, start: UTCTimeOfDay, end: UTCTimeOfDay) -> bool {
+        if start.0 <= end.0 {
+            start.0 <= self.0 && self.0 < end.0
+        } else {
+            start.0 <= self.0 || self.0 < end.0
+        }
+    }
+
+    /// Internal rounding of the time of day to the nearest multiple of `unit_nanos`, rounding half up.
+    ///
+    /// Returns the rounded time of day and the number of days carried (`0` or `1`).
+    #[inline]
+    const fn _round_to_nanos(&self, unit_nanos: u64) -> (Self, u64) {
+        let nanos = self.0 + (unit_nanos / 2);
+        let nanos = nanos - (nanos % unit_nanos);
+        // SAFETY: remainder is within NANOS_PER_DAY
+        let tod = unsafe { Self::from_nanos_unchecked(nanos % NANOS_PER_DAY) };
+        (tod, nanos / NANOS_PER_DAY)
+    }
+
+    /// Snap the time of day to the nearest whole minute, rounding half up.
+    ///
+    /// Returns the snapped time of day and the number of days carried,
+    /// which is `1` when rounding up past midnight (eg. `23:59:30` to `00:00`) and `0` otherwise.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimeOfDay;
+    ///
+    /// let tod = UTCTimeOfDay::try_from_hhmmss(10, 29, 30, 0).unwrap();
+    /// let expected = UTCTimeOfDay::try_from_hhmmss(10, 30, 0, 0).unwrap();
+    /// assert_eq!(tod.snap_to_minute(), (expected, 0));
+    ///
+    /// let tod = UTCTimeOfDay::try_from_hhmmss(23, 59, 59, 0).unwrap();
+    /// assert_eq!(tod.snap_to_minute(), (UTCTimeOfDay::ZERO, 1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn snap_to_minute(self) -> (Self, u64) {
+        self._round_to_nanos(NANOS_PER_MINUTE)
+    }
+
+    /// Snap the time of day to the nearest whole hour, rounding half up.
+    ///
+    /// Returns the snapped time of day and the number of days carried,
+    /// which is `1` when rounding up past midnight (eg. `23:30` to `00:00`) and `0` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn snap_to_hour(self) -> (Self, u64) {
+        self._round_to_nanos(NANOS_PER_HOUR)
+    }
+
+    /// Wrapping addition of a duration to the time of day. Computes `self + rhs`,
+    /// wrapping around midnight and discarding any whole days carried.
+    ///
+    /// eg. `23:00 + 2h = 01:00`
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_add_duration(self, rhs: Duration) -> Self {
+        let rhs = (rhs.as_nanos() % NANOS_PER_DAY as u128) as u64;
+        // SAFETY: remainder is within NANOS_PER_DAY
+        unsafe { Self::from_nanos_unchecked((self.0 + rhs) % NANOS_PER_DAY) }
+    }
+
+    /// Wrapping subtraction of a duration from the time of day. Computes `self - rhs`,
+    /// wrapping around midnight and discarding any whole days borrowed.
+    ///
+    /// eg. `01:00 - 2h = 23:00`
+    #[inline]
+    #[must_use]
+    pub const fn wrapping_sub_duration(self, rhs: Duration) -> Self {
+        let rhs = (rhs.as_nanos() % NANOS_PER_DAY as u128) as u64;
+        // SAFETY: remainder is within NANOS_PER_DAY
+        unsafe { Self::from_nanos_unchecked((self.0 + NANOS_PER_DAY - rhs) % NANOS_PER_DAY) }
+    }
+
+    /// Map the time of day uniformly into one of `buckets` equal width buckets,
+    /// returning a bucket index in the range `[0, buckets)`.
+    ///
+    /// Midnight maps to bucket `0` and [UTCTimeOfDay::MAX] maps to bucket `buckets - 1`.
+    /// Returns [`None`] if `buckets` is zero.
+    #[inline]
+    #[must_use]
+    pub const fn bucket_index(&self, buckets: u32) -> Option<u32> {
+        if buckets == 0 {
+            return None;
+        }
+        // widen to avoid overflow, nanos in a day * u32::MAX exceeds u64::MAX
+        Some(((self.0 as u128 * buckets as u128) / NANOS_PER_DAY as u128) as u32)
+    }
+
     /// Time of day from UTC timestamp
+    #[must_use]
     pub const fn from_timestamp(timestamp: UTCTimestamp) -> Self {
         timestamp.as_tod()
     }
@@ -1209,32 +2442,151 @@ impl UTCTimeOfDay {
                 Self::MIN_ISO_TOD_LEN,
             ));
         }
-        let (hour_str, rem) = iso[1..].split_at(2); // remainder = ":mm:ss.nnnZ"
-        let (minute_str, rem) = rem[1..].split_at(2); // remainder = ":ss.nnnZ"
-        let (second_str, rem) = rem[1..].split_at(2); // remainder = ".nnnZ"
-        let hrs: u8 = hour_str.parse()?;
-        let mins: u8 = minute_str.parse()?;
-        let secs: u8 = second_str.parse()?;
-        // calculate subseconds
-        let rem_len = rem.len();
-        let subsec_ns: u32 = if rem_len > 1 {
-            let subsec_str = &rem[1..(rem_len - 1)]; // "nnn"
-            let precision: u32 = subsec_str.len() as u32;
-            if precision > Self::MAX_ISO_TOD_PRECISION as u32 {
-                return Err(UTCTimeOfDayError::ExcessPrecision(precision));
-            }
-            if precision == 0 {
-                0
-            } else {
-                let subsec: u32 = subsec_str.parse()?;
-                subsec * 10u32.pow(Self::MAX_ISO_TOD_PRECISION as u32 - precision)
+        // validate separators, which also ensures the slicing below falls on char boundaries
+        let bytes = iso.as_bytes();
+        let separators = [(0, b'T'), (3, b':'), (6, b':'), (len - 1, b'Z')];
+        for (offset, expected) in separators {
+            if bytes[offset] != expected {
+                return Err(UTCTimeOfDayError::InvalidSeparator(offset));
             }
+        }
+        // without a decimal component, the zone designator directly follows the seconds
+        if len > Self::MIN_ISO_TOD_LEN && bytes[9] != b'.' {
+            return Err(UTCTimeOfDayError::InvalidSeparator(9));
+        }
+        let hrs: u8 = Self::_parse_component(&iso[1..3], TimeComponent::Hour, 1)?;
+        let mins: u8 = Self::_parse_component(&iso[4..6], TimeComponent::Minute, 4)?;
+        let secs: u8 = Self::_parse_component(&iso[7..9], TimeComponent::Second, 7)?;
+        // calculate subseconds, a decimal point without digits is taken as zero
+        let subsec_ns: u32 = if len > Self::MIN_ISO_TOD_LEN {
+            let subsec_str = &iso[10..(len - 1)]; // "nnn"
+            Self::_subsec_ns_from_str(subsec_str).map_err(|e| match e {
+                UTCTimeOfDayError::ParseErr(e) => UTCTimeOfDayError::InvalidComponent(
+                    TimeComponent::Subsecond,
+                    10,
+                    IsoStr::_from_str(subsec_str),
+                    e,
+                ),
+                e => e,
+            })?
         } else {
             0
         };
         Self::try_from_hhmmss(hrs, mins, secs, subsec_ns)
     }
 
+    /// Try parse time of day from a 12-hour clock str in the format:
+    /// * `hh:mm AM` or
+    /// * `hh:mm:ss PM`
+    ///
+    /// Hours are `[1, 12]` and may be a single digit (eg. `9:05 AM`).
+    /// The `AM`/`PM` designator ignores ASCII case, and the space preceding it is optional.
+    /// Midnight is `12:00 AM` and noon is `12:00 PM`.
+    ///
+    /// This is not an ISO 8601 format, see [UTCTimeOfDay::try_from_iso_tod] for 24-hour strs.
+    ///
+    /// ## Examples
+    #[cfg_attr(not(feature = "std"), doc = "```rust,ignore")]
+    #[cfg_attr(feature = "std", doc = "```rust")]
+    /// use utc_dt::time::UTCTimeOfDay;
+    ///
+    /// let tod = UTCTimeOfDay::try_from_12h("10:18:08 PM").unwrap();
+    /// assert_eq!(tod, UTCTimeOfDay::try_from_hhmmss(22, 18, 8, 0).unwrap());
+    /// assert_eq!(UTCTimeOfDay::try_from_12h("12:00 AM").unwrap(), UTCTimeOfDay::ZERO);
+    /// ```
+    pub fn try_from_12h(s: &str) -> Result<Self, UTCTimeOfDayError> {
+        // ASCII only, which ensures the slicing below falls on char boundaries
+        if let Some(offset) = s.bytes().position(|b| !b.is_ascii()) {
+            return Err(UTCTimeOfDayError::InvalidSeparator(offset));
+        }
+        let len = s.len();
+        let (time, designator) = s.split_at(len.saturating_sub(2));
+        let is_pm = if designator.eq_ignore_ascii_case("AM") {
+            false
+        } else if designator.eq_ignore_ascii_case("PM") {
+            true
+        } else {
+            return Err(UTCTimeOfDayError::InvalidSeparator(time.len()));
+        };
+        let time = time.strip_suffix(' ').unwrap_or(time);
+        // layout `h:mm`, `hh:mm`, `h:mm:ss` or `hh:mm:ss`
+        let bytes = time.as_bytes();
+        let hrs_len = if bytes.get(1) == Some(&b':') { 1 } else { 2 };
+        let has_secs = match time.len().checked_sub(hrs_len) {
+            Some(3) => false,
+            Some(6) => true,
+            _ => return Err(UTCTimeOfDayError::InvalidSeparator(time.len())),
+        };
+        if bytes[hrs_len] != b':' {
+            return Err(UTCTimeOfDayError::InvalidSeparator(hrs_len));
+        }
+        if has_secs && bytes[hrs_len + 3] != b':' {
+            return Err(UTCTimeOfDayError::InvalidSeparator(hrs_len + 3));
+        }
+        let hrs: u8 = Self::_parse_component(&time[..hrs_len], TimeComponent::Hour, 0)?;
+        let mins_offset = hrs_len + 1;
+        let mins_str = &time[mins_offset..(mins_offset + 2)];
+        let mins: u8 = Self::_parse_component(mins_str, TimeComponent::Minute, mins_offset)?;
+        let secs: u8 = if has_secs {
+            let secs_offset = hrs_len + 4;
+            Self::_parse_component(&time[secs_offset..], TimeComponent::Second, secs_offset)?
+        } else {
+            0
+        };
+        // components must be within their 12-hour clock ranges
+        if !(1..=12).contains(&hrs) {
+            return Err(UTCTimeOfDayError::ComponentOutOfRange(
+                TimeComponent::Hour,
+                hrs,
+            ));
+        }
+        if mins > 59 {
+            return Err(UTCTimeOfDayError::ComponentOutOfRange(
+                TimeComponent::Minute,
+                mins,
+            ));
+        }
+        if secs > 59 {
+            return Err(UTCTimeOfDayError::ComponentOutOfRange(
+                TimeComponent::Second,
+                secs,
+            ));
+        }
+        // 12 AM is midnight, 12 PM is noon
+        let hrs = (hrs % 12) + if is_pm { 12 } else { 0 };
+        Self::try_from_hhmmss(hrs, mins, secs, 0)
+    }
+
+    /// Internal parse of an ISO time-of-day component, located at `offset` within the ISO str
+    #[inline]
+    fn _parse_component<T: FromStr<Err = ParseIntError>>(
+        component_str: &str,
+        component: TimeComponent,
+        offset: usize,
+    ) -> Result<T, UTCTimeOfDayError> {
+        component_str.parse().map_err(|e| {
+            UTCTimeOfDayError::InvalidComponent(
+                component,
+                offset,
+                IsoStr::_from_str(component_str),
+                e,
+            )
+        })
+    }
+
+    /// Internal parse of ISO decimal subsecond digits (eg. `"903"`) to nanoseconds
+    pub(crate) fn _subsec_ns_from_str(subsec_str: &str) -> Result<u32, UTCTimeOfDayError> {
+        let precision: u32 = subsec_str.len() as u32;
+        if precision > Self::MAX_ISO_TOD_PRECISION as u32 {
+            return Err(UTCTimeOfDayError::ExcessPrecision(precision));
+        }
+        if precision == 0 {
+            return Ok(0);
+        }
+        let subsec: u32 = subsec_str.parse()?;
+        Ok(subsec * 10u32.pow(Self::MAX_ISO_TOD_PRECISION as u32 - precision))
+    }
+
     /// Return time-of-day as a string in the format:
     /// * Precision = `0`: `Thh:mm:ssZ`
     /// * Precision = `3`: `Thh:mm:ss.nnnZ`
@@ -1242,20 +2594,33 @@ impl UTCTimeOfDay {
     /// Conforms to ISO 8601:
     /// <https://www.w3.org/TR/NOTE-datetime>
     #[cfg(feature = "alloc")]
+    #[must_use]
     pub fn as_iso_tod(&self, precision: usize) -> String {
-        let len = Self::iso_tod_len(precision);
-        let mut s = format!("{self}");
-        s.truncate(len - 1);
-        s.push('Z');
-        s
+        format!("{self:.precision$}")
+    }
+
+    /// Try to return time-of-day as a string, as per [UTCTimeOfDay::as_iso_tod].
+    ///
+    /// Unlike [UTCTimeOfDay::as_iso_tod], a `precision` exceeding 9 decimal places
+    /// raises [UTCTimeOfDayError::ExcessPrecision] rather than being clamped.
+    #[cfg(feature = "alloc")]
+    pub fn try_as_iso_tod(&self, precision: usize) -> Result<String, UTCTimeOfDayError> {
+        if precision > Self::MAX_ISO_TOD_PRECISION {
+            return Err(UTCTimeOfDayError::ExcessPrecision(
+                precision.min(u32::MAX as usize) as u32,
+            ));
+        }
+        Ok(self.as_iso_tod(precision))
     }
 
-    /// Internal truncated buffer write
+    /// Internal buffer write at the given precision (clamped to 9 decimal places).
+    ///
+    /// Writes exactly [UTCTimeOfDay::iso_tod_len] characters, omitting the
+    /// decimal separator for a precision of `0`.
     #[inline]
-    pub(crate) fn _write_iso_tod_trunc(&self, w: &mut StrWriter) {
+    pub(crate) fn _write_iso_tod(&self, w: &mut StrWriter, precision: usize) {
         // unwrap infallible
-        write!(w, "{self}").unwrap();
-        w.buf[w.written - 1] = b'Z';
+        write!(w, "{self:.precision$}").unwrap();
     }
 
     /// Write time-of-day to a buffer in the format:
@@ -1280,12 +2645,26 @@ impl UTCTimeOfDay {
             return Err(UTCTimeOfDayError::InsufficientStrLen(buf.len(), write_len));
         }
         let mut writer = StrWriter::new(&mut buf[..write_len]);
-        self._write_iso_tod_trunc(&mut writer);
+        self._write_iso_tod(&mut writer, precision);
         Ok(writer.written)
     }
 
+    /// Return time-of-day as a stack allocated ISO str, with a compile-time precision `P`:
+    /// * `P` = `0`: `Thh:mm:ssZ`
+    /// * `P` = `3`: `Thh:mm:ss.nnnZ`
+    ///
+    /// Precision is limited to 9 decimal places (nanosecond precision).
+    ///
+    /// Conforms to ISO 8601:
+    /// <https://www.w3.org/TR/NOTE-datetime>
+    #[must_use]
+    pub fn to_iso_array<const P: usize>(&self) -> IsoStr<{ Self::MAX_ISO_TOD_LEN }> {
+        IsoStr::_from_trunc_write(Self::iso_tod_len(P), |w| self._write_iso_tod(w, P))
+    }
+
     /// Calculate the number of characters in an ISO time-of-day str
     #[inline]
+    #[must_use]
     pub const fn iso_tod_len(precision: usize) -> usize {
         if precision == 0 {
             Self::MIN_ISO_TOD_LEN
@@ -1313,8 +2692,145 @@ pub enum UTCTimeOfDayError {
     ExcessMillis(u32),
     /// Error raised due to seconds exceeding seconds in a day
     ExcessSeconds(u32),
+    /// Error raised due to a duration exceeding the duration of a day
+    ExcessDuration(Duration),
     /// Error raised due to insufficient length of input ISO time-of-day str
     InsufficientStrLen(usize, usize),
+    /// Error raised due to a fraction of a day not within `[0.0, 1.0)`
+    ExcessFraction(f64),
+    /// Error raised due to fractional seconds not within `[0.0, 86400.0)`
+    ExcessSecondsF64(f64),
+    /// Error raised parsing a component of an ISO time-of-day str.
+    ///
+    /// Holds the component, its byte offset within the str, the offending substring
+    /// and the underlying parse error.
+    InvalidComponent(
+        TimeComponent,
+        usize,
+        IsoStr<{ UTCTimeOfDay::MAX_ISO_TOD_PRECISION }>,
+        ParseIntError,
+    ),
+    /// Error raised due to an unexpected separator or designator in an ISO time-of-day str.
+    ///
+    /// Holds the byte offset of the unexpected character within the str.
+    InvalidSeparator(usize),
+    /// Error raised due to a time component exceeding its range, eg. a 12-hour clock hour of `13`
+    ComponentOutOfRange(TimeComponent, u8),
+}
+
+impl UTCTimeOfDayError {
+    /// Internal shift of the byte offset held by [UTCTimeOfDayError::InvalidComponent]
+    /// and [UTCTimeOfDayError::InvalidSeparator], for time-of-day strs nested within a larger str
+    pub(crate) fn _shift_offset(self, shift: usize) -> Self {
+        match self {
+            Self::InvalidComponent(c, offset, substr, e) => {
+                Self::InvalidComponent(c, offset + shift, substr, e)
+            }
+            Self::InvalidSeparator(offset) => Self::InvalidSeparator(offset + shift),
+            e => e,
+        }
+    }
+}
+
+impl Add<Duration> for UTCTimeOfDay {
+    type Output = UTCTimeOfDay;
+
+    /// Adds the duration, wrapping around midnight.
+    ///
+    /// The time of day is cyclic, whole days carried are discarded (eg. `23:00 + 2h = 01:00`).
+    /// See [UTCTimeOfDay::wrapping_add_duration].
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.wrapping_add_duration(rhs)
+    }
+}
+
+impl AddAssign<Duration> for UTCTimeOfDay {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub<Duration> for UTCTimeOfDay {
+    type Output = UTCTimeOfDay;
+
+    /// Subtracts the duration, wrapping around midnight.
+    ///
+    /// The time of day is cyclic, whole days borrowed are discarded (eg. `01:00 - 2h = 23:00`).
+    /// See [UTCTimeOfDay::wrapping_sub_duration].
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.wrapping_sub_duration(rhs)
+    }
+}
+
+impl SubAssign<Duration> for UTCTimeOfDay {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for UTCTimeOfDay {
+    /// Generates times of day within `[UTCTimeOfDay::ZERO, UTCTimeOfDay::MAX]`
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UTCTimeOfDay(u.int_in_range(0..=Self::MAX.0)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+/// Named components of a time-of-day
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeComponents {
+    /// Hours, `[0, 23]`
+    pub hours: u8,
+    /// Minutes, `[0, 59]`
+    pub minutes: u8,
+    /// Seconds, `[0, 59]`
+    pub seconds: u8,
+    /// Subsecond nanoseconds, `[0, 999_999_999]`
+    pub subsec_nanos: u32,
+}
+
+impl From<UTCTimeOfDay> for TimeComponents {
+    fn from(tod: UTCTimeOfDay) -> Self {
+        tod.components()
+    }
+}
+
+impl TryFrom<TimeComponents> for UTCTimeOfDay {
+    type Error = UTCTimeOfDayError;
+
+    fn try_from(components: TimeComponents) -> Result<Self, Self::Error> {
+        Self::try_from_time_components(components)
+    }
+}
+
+/// Component of a time-of-day
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeComponent {
+    /// Hour component (`hh`)
+    Hour,
+    /// Minute component (`mm`)
+    Minute,
+    /// Second component (`ss`)
+    Second,
+    /// Subsecond component (`nnn`)
+    Subsecond,
+}
+
+impl Display for TimeComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Hour => write!(f, "hour"),
+            Self::Minute => write!(f, "minute"),
+            Self::Second => write!(f, "second"),
+            Self::Subsecond => write!(f, "subsecond"),
+        }
+    }
 }
 
 impl Display for UTCTimeOfDayError {
@@ -1326,9 +2842,17 @@ impl Display for UTCTimeOfDayError {
             Self::ExcessMicros(u) => write!(f, "microseconds ({u}) not within a day"),
             Self::ExcessMillis(m) => write!(f, "milliseconds ({m}) not within a day"),
             Self::ExcessSeconds(s) => write!(f, "seconds ({s}) not within a day"),
+            Self::ExcessDuration(d) => write!(f, "duration ({d:?}) not within a day"),
             Self::InsufficientStrLen(l, m) => {
                 write!(f, "insufficient ISO time str len ({l}), {m} required")
             }
+            Self::ExcessFraction(x) => write!(f, "fraction ({x}) not within a day"),
+            Self::ExcessSecondsF64(x) => write!(f, "seconds ({x}) not within a day"),
+            Self::InvalidComponent(c, offset, substr, e) => {
+                write!(f, "invalid {c} ({substr:?}) at offset {offset}: {e}")
+            }
+            Self::InvalidSeparator(offset) => write!(f, "invalid separator at offset {offset}"),
+            Self::ComponentOutOfRange(c, v) => write!(f, "{c} ({v}) out of range"),
         }
     }
 }
@@ -1337,6 +2861,7 @@ impl Error for UTCTimeOfDayError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::ParseErr(e) => e.source(),
+            Self::InvalidComponent(_, _, _, e) => e.source(),
             _ => None,
         }
     }
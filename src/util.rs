@@ -25,3 +25,23 @@ impl core::fmt::Write for StrWriter<'_> {
         Ok(())
     }
 }
+
+/// 64-bit FNV offset basis
+const FNV1A_64_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// 64-bit FNV prime
+const FNV1A_64_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of bytes, stable across platforms and Rust versions.
+///
+/// Reference:
+/// <http://www.isthe.com/chongo/tech/comp/fnv/index.html>
+pub const fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash = FNV1A_64_OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV1A_64_PRIME);
+        i += 1;
+    }
+    hash
+}
@@ -0,0 +1,78 @@
+use utc_dt::{
+    builder::UTCDatetimeBuilder,
+    date::{DateComponent, UTCDateError},
+    time::UTCTimeOfDayError,
+    UTCDatetime, UTCDatetimeError, UTCError,
+};
+
+#[test]
+fn test_builder() -> Result<(), UTCError> {
+    // fully specified
+    let datetime = UTCDatetimeBuilder::new()
+        .year(2023)
+        .month(6)
+        .day(15)
+        .hour(10)
+        .minute(18)
+        .second(8)
+        .subsec_ns(903_000_000)
+        .build()?;
+    assert_eq!(
+        datetime,
+        UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?
+    );
+    // unset time components default to zero, in any order
+    let builder = UTCDatetimeBuilder::new().day(15).month(6).year(2023);
+    assert_eq!(
+        builder.build()?,
+        UTCDatetime::try_from_iso_datetime("2023-06-15T00:00:00Z")?
+    );
+    assert_eq!(
+        builder,
+        UTCDatetimeBuilder::default().year(2023).month(6).day(15)
+    );
+    // later calls overwrite earlier components
+    assert_eq!(
+        builder.day(16).build()?.as_date().as_components(),
+        (2023, 6, 16)
+    );
+    // missing date components
+    let result = UTCDatetimeBuilder::new().month(6).day(15).hour(10).build();
+    assert!(matches!(
+        result,
+        Err(UTCDatetimeError::MissingComponent(DateComponent::Year))
+    ));
+    let result = UTCDatetimeBuilder::new().year(2023).day(15).build();
+    assert!(matches!(
+        result,
+        Err(UTCDatetimeError::MissingComponent(DateComponent::Month))
+    ));
+    let result = UTCDatetimeBuilder::new().year(2023).month(6).build();
+    assert!(matches!(
+        result,
+        Err(UTCDatetimeError::MissingComponent(DateComponent::Day))
+    ));
+    // invalid components
+    let result = UTCDatetimeBuilder::new()
+        .year(2023)
+        .month(2)
+        .day(29)
+        .build();
+    assert!(matches!(
+        result,
+        Err(UTCDatetimeError::UTCDate(UTCDateError::DayOutOfRange(_)))
+    ));
+    let result = UTCDatetimeBuilder::new()
+        .year(2023)
+        .month(6)
+        .day(15)
+        .hour(24)
+        .build();
+    assert!(matches!(
+        result,
+        Err(UTCDatetimeError::UTCTimeOfDay(
+            UTCTimeOfDayError::ExcessNanos(_)
+        ))
+    ));
+    Ok(())
+}
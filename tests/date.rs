@@ -1,10 +1,17 @@
 use std::collections::HashSet;
+use std::num::NonZeroU8;
 
 use utc_dt::{
-    constants::{MICROS_PER_DAY, MILLIS_PER_DAY, NANOS_PER_DAY, SECONDS_PER_DAY},
-    date::UTCDate,
-    time::{UTCDay, UTCTimestamp, UTCTransformations},
-    UTCError,
+    constants::{
+        DAYS_BEFORE_MONTH, DAYS_BEFORE_MONTH_LEAP, MICROS_PER_DAY, MILLIS_PER_DAY, NANOS_PER_DAY,
+        SECONDS_PER_DAY,
+    },
+    date::{
+        md_to_ordinal, ordinal_to_md, DateComponents, Days, Month, Months, UTCDate, UTCDateError,
+        Years,
+    },
+    time::{UTCDay, UTCTimeOfDay, UTCTimestamp, UTCTransformations, Weekday},
+    UTCDatetime, UTCError,
 };
 
 #[test]
@@ -20,23 +27,186 @@ fn test_date_from_components() {
         (2023, 13, 10, false, false, 0),              // invalid date, month out of range
         (2023, 9, 31, false, false, 30),              // invalid date, day out of range
         (2023, 9, 0, false, false, 30),               // invalid date, day out of range
-        (UTCDate::MAX_YEAR, 11, 09, true, false, 30), // valid max date
+        (UTCDate::MAX_YEAR, 11, 9, true, false, 30),  // valid max date
         (UTCDate::MAX_YEAR, 12, 31, false, false, 0), // invalid max date
         (UTCDate::MAX_YEAR, u8::MAX, u8::MAX, false, false, 0), // invalid max date
     ];
 
     for (year, month, day, case_is_valid, is_leap_year, days_in_month) in test_cases {
+        assert_eq!(UTCDate::is_valid_ymd(year, month, day), case_is_valid);
         match UTCDate::try_from_components(year, month, day) {
             Ok(date) => {
                 assert!(case_is_valid);
                 assert_eq!(is_leap_year, date.is_leap_year());
                 assert_eq!(days_in_month, date.days_in_month());
+                // raw internal representation round-trips
+                let (era, yoe, month, day) = date.as_era_yoe();
+                // SAFETY: components are taken from a valid date
+                assert_eq!(
+                    unsafe { UTCDate::from_era_yoe_unchecked(era, yoe, month, day) },
+                    date
+                );
             }
             Err(_) => {
                 assert!(!case_is_valid);
             }
         }
+        // non-zero components agree with integer components
+        if let (Some(month), Some(day)) = (NonZeroU8::new(month), NonZeroU8::new(day)) {
+            assert_eq!(
+                UTCDate::from_components_nz(year, month, day).ok(),
+                UTCDate::try_from_components(year, month.get(), day.get()).ok()
+            );
+        }
+    }
+    // named components
+    let components = DateComponents {
+        year: 2023,
+        month: 6,
+        day: 15,
+    };
+    let date = UTCDate::try_from_date_components(components).unwrap();
+    assert_eq!(date.as_components(), (2023, 6, 15));
+    assert_eq!(date.components(), components);
+    assert_eq!(DateComponents::from(date), components);
+    assert_eq!(UTCDate::try_from(components).unwrap(), date);
+    let swapped = DateComponents {
+        month: 15,
+        day: 6,
+        ..components
+    };
+    assert!(UTCDate::try_from(swapped).is_err());
+    assert!(UTCDate::try_from(DateComponents::default()).is_err());
+    let date = UTCDate::from_components_nz(
+        2023,
+        NonZeroU8::new(6).unwrap(),
+        NonZeroU8::new(15).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(date.as_components(), (2023, 6, 15));
+}
+
+#[test]
+fn test_date_days_before_month() -> Result<(), UTCError> {
+    assert_eq!(DAYS_BEFORE_MONTH[0], 0);
+    assert_eq!(DAYS_BEFORE_MONTH_LEAP[0], 0);
+    assert_eq!(DAYS_BEFORE_MONTH[12], 365);
+    assert_eq!(DAYS_BEFORE_MONTH_LEAP[12], 366);
+    let (mut common_days, mut leap_days) = (0, 0);
+    for month in 1..=12 {
+        let common = UTCDate::try_from_components(2023, month, 1)?;
+        let leap = UTCDate::try_from_components(2024, month, 1)?;
+        let index = month as usize - 1;
+        assert_eq!(DAYS_BEFORE_MONTH[index], common_days);
+        assert_eq!(DAYS_BEFORE_MONTH_LEAP[index], leap_days);
+        // offsets match days since the start of the year
+        let year_start = UTCDate::try_from_components(2023, 1, 1)?.as_day();
+        assert_eq!((common.as_day() - year_start).as_u64(), common_days as u64);
+        let year_start = UTCDate::try_from_components(2024, 1, 1)?.as_day();
+        assert_eq!((leap.as_day() - year_start).as_u64(), leap_days as u64);
+        common_days += common.days_in_month() as u16;
+        leap_days += leap.days_in_month() as u16;
     }
+    assert_eq!(common_days, 365);
+    assert_eq!(leap_days, 366);
+    Ok(())
+}
+
+#[test]
+fn test_date_ordinals() -> Result<(), UTCError> {
+    // day 60 in common and leap years
+    assert_eq!(ordinal_to_md(2023, 60), Some((3, 1)));
+    assert_eq!(ordinal_to_md(2024, 60), Some((2, 29)));
+    assert_eq!(ordinal_to_md(1900, 60), Some((3, 1)));
+    assert_eq!(ordinal_to_md(2000, 60), Some((2, 29)));
+    assert_eq!(md_to_ordinal(2023, 3, 1), Some(60));
+    assert_eq!(md_to_ordinal(2024, 2, 29), Some(60));
+    assert_eq!(md_to_ordinal(2024, 3, 1), Some(61));
+    // bounds
+    assert_eq!(ordinal_to_md(2023, 1), Some((1, 1)));
+    assert_eq!(ordinal_to_md(2023, 365), Some((12, 31)));
+    assert_eq!(ordinal_to_md(2024, 366), Some((12, 31)));
+    assert_eq!(ordinal_to_md(2023, 0), None);
+    assert_eq!(ordinal_to_md(2023, 366), None);
+    assert_eq!(ordinal_to_md(2024, 367), None);
+    assert_eq!(md_to_ordinal(2023, 2, 29), None);
+    assert_eq!(md_to_ordinal(2023, 0, 1), None);
+    assert_eq!(md_to_ordinal(2023, 13, 1), None);
+    assert_eq!(md_to_ordinal(2023, 1, 0), None);
+    assert_eq!(md_to_ordinal(2023, 12, 32), None);
+    // year navigation
+    let date = UTCDate::try_from_components(2024, 6, 15)?;
+    assert_eq!(date.day_of_year(), 167);
+    assert_eq!(
+        date.start_of_year(),
+        UTCDate::try_from_components(2024, 1, 1)?
+    );
+    assert_eq!(
+        date.end_of_year(),
+        UTCDate::try_from_components(2024, 12, 31)?
+    );
+    assert_eq!(date.start_of_year().day_of_year(), 1);
+    assert_eq!(date.end_of_year().day_of_year(), 366);
+    assert_eq!(UTCDate::MIN.start_of_year(), UTCDate::MIN);
+    assert_eq!(UTCDate::MAX.end_of_year(), UTCDate::MAX);
+    assert_eq!(
+        UTCDate::MAX.start_of_year(),
+        UTCDate::try_from_components(UTCDate::MAX_YEAR, 1, 1)?
+    );
+    // first day of a century year of era
+    let march_2100 = UTCDate::try_from_components(2100, 3, 1)?;
+    assert_eq!(UTCDate::from_day(march_2100.as_day()), march_2100);
+    assert_eq!(march_2100.day_of_year(), 60);
+    for day in (0..UTCDate::MAX.as_day().as_u64()).step_by(7_919_993) {
+        let date = UTCDate::from_day(UTCDay::try_from_u64(day)?);
+        let (year, _, _) = date.as_components();
+        assert_eq!(UTCDate::from_ordinal(year, date.day_of_year())?, date);
+    }
+    assert!(UTCDate::from_ordinal(2023, 0).is_err());
+    assert!(UTCDate::from_ordinal(2023, 366).is_err());
+    assert_eq!(
+        UTCDate::from_ordinal(2024, 366)?,
+        UTCDate::try_from_components(2024, 12, 31)?
+    );
+    assert!(UTCDate::from_ordinal(1969, 1).is_err());
+    assert!(UTCDate::from_ordinal(UTCDate::MAX_YEAR, 365).is_err());
+    // leap day ordinals report the maximum for the year
+    assert_eq!(
+        UTCDate::from_ordinal(2024, 366)?,
+        UTCDate::try_from_components(2024, 12, 31)?
+    );
+    let err = UTCDate::from_ordinal(2023, 366).unwrap_err();
+    assert!(matches!(
+        err,
+        UTCDateError::OrdinalOutOfRange {
+            year: 2023,
+            ordinal: 366,
+            max: 365
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "ordinal day 366 out of range for 2023 (max 365)"
+    );
+    assert!(matches!(
+        UTCDate::from_ordinal(2024, 0),
+        Err(UTCDateError::OrdinalOutOfRange { max: 366, .. })
+    ));
+    assert!(matches!(
+        UTCDate::from_ordinal(1969, 1),
+        Err(UTCDateError::YearOutOfRange(1969))
+    ));
+    // round trip every day of a common and leap year
+    for (year, days_in_year) in [(2023, 365), (2024, 366)] {
+        let mut date = UTCDate::try_from_components(year, 1, 1)?;
+        for ordinal in 1..=days_in_year {
+            let (_, month, day) = date.as_components();
+            assert_eq!(ordinal_to_md(year, ordinal), Some((month, day)));
+            assert_eq!(md_to_ordinal(year, month, day), Some(ordinal));
+            date += Days(1);
+        }
+    }
+    Ok(())
 }
 
 #[test]
@@ -46,7 +216,7 @@ fn test_date_from_day() -> Result<(), UTCError> {
         (UTCDay::try_from_u64(30)?, 1970, 1, 31),
         (UTCDay::try_from_u64(19522)?, 2023, 6, 14),
         (UTCDay::try_from_u64(381112)?, 3013, 6, 14),
-        (UTCDay::MAX, UTCDate::MAX_YEAR, 11, 09),
+        (UTCDay::MAX, UTCDate::MAX_YEAR, 11, 9),
     ];
 
     for (utc_day, year, month, day) in test_cases {
@@ -62,6 +232,495 @@ fn test_date_from_day() -> Result<(), UTCError> {
     Ok(())
 }
 
+#[test]
+fn test_date_const_evaluation() {
+    const EPOCH: UTCDate = UTCDate::from_day(UTCDay::ZERO);
+    const WEEKDAYS: [(UTCDate, u8, Weekday); 2] = [
+        (
+            UTCDate::from_day(UTCDay::ZERO),
+            UTCDay::ZERO.as_weekday(),
+            UTCDay::ZERO.weekday(),
+        ),
+        (
+            UTCDate::from_day(UTCDay::MAX),
+            UTCDay::MAX.as_weekday(),
+            UTCDate::MAX.weekday(),
+        ),
+    ];
+    assert_eq!(EPOCH, UTCDate::EPOCH);
+    assert_eq!(EPOCH, UTCDate::MIN);
+    assert_eq!(EPOCH, UTCDate::from(UTCDay::ZERO));
+    assert_eq!(WEEKDAYS[0], (UTCDate::EPOCH, 4, Weekday::Thursday));
+    assert_eq!(WEEKDAYS[1].0, UTCDate::MAX);
+    assert_eq!(WEEKDAYS[1].1, WEEKDAYS[1].2.as_u8());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "unchecked UTCDate month or day out of range")]
+fn test_date_unchecked_debug_assertion() {
+    // SAFETY: deliberately invalid, to test the debug assertion
+    let _ = unsafe { UTCDate::from_components_unchecked(2023, 2, 29) };
+}
+
+#[test]
+fn test_date_julian_calendar() -> Result<(), UTCError> {
+    let test_cases = [
+        ((1970, 1, 1), (1969, 12, 19)),
+        ((2000, 1, 1), (1999, 12, 19)),
+        ((2023, 6, 14), (2023, 6, 1)),
+        ((2100, 2, 28), (2100, 2, 15)),
+        ((2100, 3, 14), (2100, 2, 29)), // julian leap day, gregorian common year
+        ((2100, 3, 15), (2100, 3, 1)),
+    ];
+
+    for ((year, month, day), julian) in test_cases {
+        let date = UTCDate::try_from_components(year, month, day)?;
+        assert_eq!(date.to_julian_calendar(), julian);
+    }
+    // the epoch is the earliest convertible date, trailing the gregorian calendar by 13 days
+    assert_eq!(UTCDate::EPOCH.to_julian_calendar(), (1969, 12, 19));
+    assert_eq!(
+        UTCDate::EPOCH
+            .checked_add_days(13)
+            .unwrap()
+            .to_julian_calendar(),
+        (1970, 1, 1)
+    );
+    // test limits do not overflow
+    let (year, _, _) = UTCDate::MAX.to_julian_calendar();
+    assert!(year <= UTCDate::MAX_YEAR);
+
+    Ok(())
+}
+
+#[test]
+fn test_date_weekdays() -> Result<(), UTCError> {
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    assert_eq!(date.weekday(), Weekday::Thursday);
+    assert_eq!(UTCDate::MIN.weekday(), Weekday::Thursday);
+    // test next weekday
+    assert_eq!(date.next_weekday_inclusive(Weekday::Thursday), Some(date));
+    assert_eq!(
+        date.next_weekday_inclusive(Weekday::Monday),
+        Some(UTCDate::try_from_components(2023, 6, 19)?)
+    );
+    assert_eq!(
+        date.next_weekday_inclusive(Weekday::Wednesday),
+        Some(UTCDate::try_from_components(2023, 6, 21)?)
+    );
+    assert_eq!(
+        date.checked_add_days(17),
+        Some(UTCDate::try_from_components(2023, 7, 2)?)
+    );
+    assert_eq!(UTCDate::MAX.checked_add_days(1), None);
+    assert_eq!(UTCDate::MAX.checked_add_days(u64::MAX), None);
+    assert_eq!(
+        UTCDate::MAX.next_weekday_inclusive(UTCDate::MAX.weekday()),
+        Some(UTCDate::MAX)
+    );
+    // test iterate weekdays, mondays of june 2023
+    let start = UTCDate::try_from_components(2023, 6, 1)?;
+    let end = UTCDate::try_from_components(2023, 6, 30)?;
+    let mondays: Vec<UTCDate> =
+        UTCDate::iter_weekday_between(start, end, Weekday::Monday).collect();
+    assert_eq!(mondays.len(), 4);
+    assert_eq!(mondays[0], UTCDate::try_from_components(2023, 6, 5)?);
+    assert_eq!(mondays[3], UTCDate::try_from_components(2023, 6, 26)?);
+    assert!(mondays.iter().all(|d| d.weekday() == Weekday::Monday));
+    // thursdays of june 2023, bounds inclusive
+    let end = UTCDate::try_from_components(2023, 6, 29)?;
+    assert_eq!(
+        UTCDate::iter_weekday_between(start, end, Weekday::Thursday).count(),
+        5
+    );
+    // empty range
+    assert_eq!(
+        UTCDate::iter_weekday_between(end, start, Weekday::Thursday).count(),
+        0
+    );
+    // count weekdays in a month, agreeing with iteration
+    assert_eq!(
+        UTCDate::count_weekday_in_month(2023, 6, Weekday::Monday)?,
+        4
+    );
+    assert_eq!(
+        UTCDate::count_weekday_in_month(2023, 7, Weekday::Monday)?,
+        5
+    );
+    assert_eq!(
+        UTCDate::count_weekday_in_month(2023, 2, Weekday::Monday)?,
+        4
+    );
+    assert_eq!(
+        UTCDate::count_weekday_in_month(2024, 2, Weekday::Thursday)?,
+        5
+    );
+    for month in 1..=12 {
+        let start = UTCDate::try_from_components(2024, month, 1)?;
+        let end = UTCDate::try_from_components(2024, month, start.days_in_month())?;
+        let total: u8 = Weekday::ALL
+            .iter()
+            .map(|weekday| {
+                let count = UTCDate::count_weekday_in_month(2024, month, *weekday).unwrap();
+                assert_eq!(
+                    UTCDate::iter_weekday_between(start, end, *weekday).count(),
+                    count as usize
+                );
+                count
+            })
+            .sum();
+        assert_eq!(total, start.days_in_month());
+    }
+    assert!(UTCDate::count_weekday_in_month(2023, 13, Weekday::Monday).is_err());
+    assert!(UTCDate::count_weekday_in_month(1969, 1, Weekday::Monday).is_err());
+    // iteration terminates at the maximum date
+    let start = UTCDate::from_day(UTCDay::MAX - 20);
+    assert_eq!(
+        UTCDate::iter_weekday_between(start, UTCDate::MAX, Weekday::Monday).count(),
+        3
+    );
+    Ok(())
+}
+
+#[test]
+fn test_date_calendar_arithmetic() -> Result<(), UTCError> {
+    // days crossing a month boundary
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    assert_eq!(date + Days(30), UTCDate::try_from_components(2023, 7, 15)?);
+    assert_eq!(date - Days(30), UTCDate::try_from_components(2023, 5, 16)?);
+    let mut date_mut = date;
+    date_mut += Days(17);
+    assert_eq!(date_mut, UTCDate::try_from_components(2023, 7, 2)?);
+    date_mut -= Days(17);
+    assert_eq!(date_mut, date);
+    assert_eq!(UTCDate::MIN.checked_sub_days(1), None);
+    assert_eq!(UTCDate::MAX.checked_sub_days(0), Some(UTCDate::MAX));
+    assert_eq!(
+        UTCDate::MAX.checked_sub_days(UTCDate::MAX.as_day().as_u64()),
+        Some(UTCDate::MIN)
+    );
+    // months, clamping to the end of month
+    let date = UTCDate::try_from_components(2024, 1, 31)?;
+    assert_eq!(date + Months(1), UTCDate::try_from_components(2024, 2, 29)?);
+    assert_eq!(
+        date + Months(13),
+        UTCDate::try_from_components(2025, 2, 28)?
+    );
+    assert_eq!(
+        date + Months(11),
+        UTCDate::try_from_components(2024, 12, 31)?
+    );
+    assert_eq!(
+        date + Months(12),
+        UTCDate::try_from_components(2025, 1, 31)?
+    );
+    assert_eq!(
+        date - Months(2),
+        UTCDate::try_from_components(2023, 11, 30)?
+    );
+    date_mut = date;
+    date_mut += Months(3);
+    assert_eq!(date_mut, UTCDate::try_from_components(2024, 4, 30)?);
+    date_mut -= Months(3);
+    assert_eq!(date_mut, UTCDate::try_from_components(2024, 1, 30)?);
+    assert_eq!(UTCDate::MIN.checked_sub_months(1), None);
+    assert_eq!(UTCDate::MAX.checked_add_months(1), None);
+    assert_eq!(UTCDate::MAX.checked_add_months(u64::MAX), None);
+    assert_eq!(
+        UTCDate::MAX.checked_sub_months(1),
+        Some(UTCDate::try_from_components(UTCDate::MAX_YEAR, 10, 9)?)
+    );
+    // single month steps, clamping to the end of month
+    assert_eq!(
+        date.checked_next_month(),
+        Some(UTCDate::try_from_components(2024, 2, 29)?)
+    );
+    assert_eq!(
+        UTCDate::try_from_components(2023, 1, 31)?.checked_next_month(),
+        Some(UTCDate::try_from_components(2023, 2, 28)?)
+    );
+    assert_eq!(
+        UTCDate::try_from_components(2024, 3, 31)?.checked_prev_month(),
+        Some(UTCDate::try_from_components(2024, 2, 29)?)
+    );
+    assert_eq!(
+        UTCDate::try_from_components(2024, 12, 31)?.checked_prev_month(),
+        Some(UTCDate::try_from_components(2024, 11, 30)?)
+    );
+    assert_eq!(
+        date.checked_prev_month(),
+        Some(UTCDate::try_from_components(2023, 12, 31)?)
+    );
+    assert_eq!(UTCDate::MAX.checked_next_month(), None);
+    assert_eq!(
+        UTCDate::MAX.checked_prev_month(),
+        UTCDate::MAX.checked_sub_months(1)
+    );
+    assert_eq!(UTCDate::MIN.checked_prev_month(), None);
+    assert_eq!(
+        UTCDate::MIN.checked_next_month(),
+        Some(UTCDate::try_from_components(1970, 2, 1)?)
+    );
+    // years, clamping leap days
+    let leap_day = UTCDate::try_from_components(2024, 2, 29)?;
+    assert_eq!(
+        leap_day + Years(1),
+        UTCDate::try_from_components(2025, 2, 28)?
+    );
+    assert_eq!(leap_day + Years(4), leap_day + Months(48));
+    assert_eq!(
+        leap_day - Years(4),
+        UTCDate::try_from_components(2020, 2, 29)?
+    );
+    date_mut = leap_day;
+    date_mut -= Years(54);
+    assert_eq!(date_mut, UTCDate::try_from_components(1970, 2, 28)?);
+    date_mut += Years(54);
+    assert_eq!(date_mut, UTCDate::try_from_components(2024, 2, 28)?);
+    assert_eq!(UTCDate::MIN.checked_sub_years(1), None);
+    assert_eq!(UTCDate::MAX.checked_add_years(1), None);
+    assert_eq!(UTCDate::MIN.checked_add_years(u64::MAX), None);
+    // signed days
+    let earlier = UTCDate::try_from_components(2023, 12, 31)?;
+    let later = UTCDate::try_from_components(2024, 1, 1)?;
+    assert!(earlier < later);
+    assert_eq!(later.signed_days_since(earlier), 1);
+    assert_eq!(earlier.signed_days_since(later), -1);
+    assert_eq!(earlier.signed_days_since(earlier), 0);
+    assert_eq!(
+        UTCDate::MAX.signed_days_since(UTCDate::MIN),
+        UTCDate::MAX.as_day().as_u64() as i64
+    );
+    // years and days, spanning a leap year
+    let earlier = UTCDate::try_from_components(2022, 1, 15)?;
+    let later = UTCDate::try_from_components(2024, 2, 24)?;
+    assert_eq!(earlier.completed_years_since(later), 2);
+    assert_eq!(later.years_and_days_since(earlier), (2, 40));
+    assert_eq!(earlier.years_and_days_since(later), (-2, 40));
+    let later = UTCDate::try_from_components(2025, 1, 14)?;
+    assert_eq!(later.years_and_days_since(earlier), (2, 365));
+    let leap_day = UTCDate::try_from_components(2024, 2, 29)?;
+    let later = UTCDate::try_from_components(2025, 2, 28)?;
+    assert_eq!(later.years_and_days_since(leap_day), (1, 0));
+    let later = UTCDate::try_from_components(2025, 3, 1)?;
+    assert_eq!(later.years_and_days_since(leap_day), (1, 1));
+    assert_eq!(leap_day.years_and_days_since(leap_day), (0, 0));
+    assert_eq!(
+        UTCDate::try_from_components(2023, 2, 28)?.years_and_days_since(leap_day),
+        (-1, 1)
+    );
+    assert_eq!(
+        UTCDate::MIN.completed_years_since(UTCDate::MAX),
+        UTCDate::MAX_YEAR - UTCDate::MIN_YEAR
+    );
+    // completed months, exact spans
+    let date = UTCDate::try_from_components(2023, 1, 15)?;
+    assert_eq!(date.completed_months_since(date), 0);
+    assert_eq!(date.completed_months_since(date + Months(1)), 1);
+    assert_eq!(date.completed_months_since(date + Years(2)), 24);
+    // partial months
+    assert_eq!(date.completed_months_since(date + Days(30)), 0);
+    assert_eq!(date.completed_months_since(date + Days(45)), 1);
+    assert_eq!(date.completed_months_since(date + Months(1) - Days(1)), 0);
+    assert_eq!(date.completed_months_since(date + Months(13) - Days(1)), 12);
+    // as_of preceding the date
+    assert_eq!((date + Days(1)).completed_months_since(date), 0);
+    assert_eq!(UTCDate::MAX.completed_months_since(UTCDate::MIN), 0);
+    // month-end boundaries are clamped
+    let date = UTCDate::try_from_components(2023, 1, 31)?;
+    let feb_27 = UTCDate::try_from_components(2023, 2, 27)?;
+    let feb_28 = UTCDate::try_from_components(2023, 2, 28)?;
+    assert_eq!(date.completed_months_since(feb_27), 0);
+    assert_eq!(date.completed_months_since(feb_28), 1);
+    assert_eq!(
+        date.completed_months_since(UTCDate::try_from_components(2023, 3, 30)?),
+        1
+    );
+    assert_eq!(
+        date.completed_months_since(UTCDate::try_from_components(2023, 3, 31)?),
+        2
+    );
+    assert_eq!(
+        date.completed_months_since(UTCDate::try_from_components(2023, 4, 30)?),
+        3
+    );
+    // consistent with month addition
+    for months in 0..48 {
+        let as_of = date + Months(months);
+        assert_eq!(date.completed_months_since(as_of), months);
+    }
+    assert_eq!(
+        UTCDate::MIN.completed_months_since(UTCDate::MAX),
+        (UTCDate::MAX_YEAR - UTCDate::MIN_YEAR) * 12 + 10
+    );
+    assert_eq!(
+        UTCDate::MIN.checked_add_years(UTCDate::MAX_YEAR - UTCDate::MIN_YEAR),
+        Some(UTCDate::try_from_components(UTCDate::MAX_YEAR, 1, 1)?)
+    );
+    // test fiscal years, starting in april
+    let test_cases = [
+        ((2023, 1, 1), 2022, 4),
+        ((2023, 3, 15), 2022, 4),
+        ((2023, 3, 31), 2022, 4),
+        ((2023, 4, 1), 2023, 1),
+        ((2023, 6, 30), 2023, 1),
+        ((2023, 7, 1), 2023, 2),
+        ((2023, 10, 1), 2023, 3),
+        ((2023, 12, 31), 2023, 3),
+        ((2024, 1, 1), 2023, 4),
+        ((1970, 1, 1), 1969, 4),
+    ];
+    for ((year, month, day), fiscal_year, fiscal_quarter) in test_cases {
+        let date = UTCDate::try_from_components(year, month, day)?;
+        assert_eq!(date.fiscal_year(4), Some(fiscal_year));
+        assert_eq!(date.fiscal_quarter(4), Some(fiscal_quarter));
+        // january start matches the calendar year
+        assert_eq!(date.fiscal_year(1), Some(year));
+        assert_eq!(date.fiscal_quarter(1), Some((month - 1) / 3 + 1));
+    }
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    assert_eq!(date.fiscal_year(0), None);
+    assert_eq!(date.fiscal_quarter(13), None);
+    assert_eq!(date.fiscal_year(12), Some(2022));
+    assert_eq!(date.fiscal_quarter(12), Some(3));
+    // test calendar grouping comparisons
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    let same_month = UTCDate::try_from_components(2023, 6, 1)?;
+    let same_year = UTCDate::try_from_components(2023, 1, 15)?;
+    let next_year = UTCDate::try_from_components(2024, 6, 15)?;
+    assert!(date.is_same_month(&date));
+    assert!(date.is_same_month(&same_month));
+    assert!(date.is_same_year(&same_month));
+    assert!(!date.is_same_month(&same_year));
+    assert!(date.is_same_year(&same_year));
+    assert!(!date.is_same_month(&next_year));
+    assert!(!date.is_same_year(&next_year));
+    // january and february are stored with the preceding year's era offset
+    let jan = UTCDate::try_from_components(2024, 1, 31)?;
+    let feb = UTCDate::try_from_components(2024, 2, 1)?;
+    let dec = UTCDate::try_from_components(2023, 12, 31)?;
+    assert!(jan.is_same_year(&feb));
+    assert!(!jan.is_same_month(&feb));
+    assert!(!jan.is_same_year(&dec));
+    Ok(())
+}
+
+#[test]
+fn test_date_easter() -> Result<(), UTCError> {
+    let test_cases = [
+        (1970, (3, 29), (4, 26)),
+        (2000, (4, 23), (4, 30)),
+        (2008, (3, 23), (4, 27)),
+        (2011, (4, 24), (4, 24)),
+        (2019, (4, 21), (4, 28)),
+        (2024, (3, 31), (5, 5)),
+        (2025, (4, 20), (4, 20)),
+        (2038, (4, 25), (4, 25)),
+        (2285, (3, 22), (4, 26)),
+    ];
+    for (year, (month, day), (orthodox_month, orthodox_day)) in test_cases {
+        let easter = UTCDate::easter_sunday(year)?;
+        assert_eq!(easter, UTCDate::try_from_components(year, month, day)?);
+        assert_eq!(easter.weekday(), Weekday::Sunday);
+        let orthodox = UTCDate::orthodox_easter(year)?;
+        assert_eq!(
+            orthodox,
+            UTCDate::try_from_components(year, orthodox_month, orthodox_day)?
+        );
+        assert_eq!(orthodox.weekday(), Weekday::Sunday);
+        assert!(orthodox >= easter);
+    }
+    // out of range years
+    assert!(UTCDate::easter_sunday(1969).is_err());
+    assert!(UTCDate::orthodox_easter(1969).is_err());
+    assert!(UTCDate::easter_sunday(UTCDate::MAX_YEAR + 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_date_month_names() -> Result<(), UTCError> {
+    // months agree with numerical forms and names
+    for (i, month) in Month::ALL.into_iter().enumerate() {
+        assert_eq!(month.as_u8() as usize, i + 1);
+        assert_eq!(Month::try_from_u8(month.as_u8())?, month);
+        assert_eq!(Month::from_english(month.name()), Some(month));
+        assert_eq!(Month::from_english(month.abbreviation()), Some(month));
+        assert!(month.name().starts_with(month.abbreviation()));
+    }
+    assert!(Month::try_from_u8(0).is_err());
+    assert!(Month::try_from_u8(13).is_err());
+    assert_eq!(Month::June.abbreviation(), "Jun");
+    assert_eq!(Month::from_english("SEPTEMBER"), Some(Month::September));
+    assert_eq!(Month::from_english("sep"), Some(Month::September));
+    assert_eq!(Month::from_english("Sept"), None);
+    assert_eq!(Month::from_english(""), None);
+    #[cfg(feature = "alloc")]
+    assert_eq!(Month::May.to_string(), "May");
+    // parse english dates
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    for english in [
+        "15 June 2023",
+        "Jun 15 2023",
+        "June 15, 2023",
+        "15 jun 2023",
+        "15 JUNE, 2023",
+        "  Jun  15,  2023 ",
+    ] {
+        assert_eq!(UTCDate::try_from_english(english)?, date);
+    }
+    assert_eq!(UTCDate::try_from_english("1 Jan 1970")?, UTCDate::MIN);
+    assert_eq!(
+        UTCDate::try_from_english("November 9 584554051223")?,
+        UTCDate::MAX
+    );
+    // invalid dates raise domain errors
+    assert!(matches!(
+        UTCDate::try_from_english("29 Feb 2023"),
+        Err(UTCDateError::DayOutOfRange(_))
+    ));
+    // day errors report the valid maximum for the month
+    let err = UTCDate::try_from_components(2023, 2, 30).unwrap_err();
+    assert!(matches!(err, UTCDateError::DayOutOfRange(_)));
+    assert_eq!(
+        err.to_string(),
+        "day 30 out of range for February 2023 (max 28)"
+    );
+    let err = UTCDate::try_from_components(2024, 4, 31).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 31 out of range for April 2024 (max 30)"
+    );
+    let err = UTCDate::try_from_components(2024, 2, 30).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 30 out of range for February 2024 (max 29)"
+    );
+    assert!(matches!(
+        UTCDate::try_from_english("31 Dec 1969"),
+        Err(UTCDateError::YearOutOfRange(1969))
+    ));
+    // unsupported formats
+    for invalid in [
+        "",
+        "2023-06-15",
+        "15 2023",
+        "Jun June 2023",
+        "15 16 2023",
+        "15 Juno 2023",
+        "Thu 15 June 2023",
+        "+15 Jun 2023",
+        "015 Jun 2023",
+        "15 Jun -2023",
+    ] {
+        assert!(matches!(
+            UTCDate::try_from_english(invalid),
+            Err(UTCDateError::InvalidFormat)
+        ));
+    }
+    Ok(())
+}
+
 #[test]
 fn test_date_iso_conversions() -> Result<(), UTCError> {
     let test_cases = [
@@ -79,8 +738,20 @@ fn test_date_iso_conversions() -> Result<(), UTCError> {
         (2023, 9, 0, false, "2023-0a-00"),   // invalid date, month not integer
         (2023, 9, 0, false, "2023-09-0a"),   // invalid date, day not integer
         (2023, 9, 1, false, "2023-09-1"),    // invalid date, incorrect formatting
+        (10000, 1, 1, true, "+10000-01-01"), // valid expanded year
+        (UTCDate::MAX_YEAR, 11, 9, true, "+584554051223-11-09"), // valid maximum date
+        (UTCDate::MAX_YEAR, 11, 10, false, "+584554051223-11-10"), // invalid after maximum date
+        (2023, 6, 14, false, "+2023-06-14"), // invalid expanded year, too few digits
+        (10000, 1, 1, false, "10000-01-01"), // invalid expanded year, no sign
+        (10000, 1, 1, false, "+1000000000000000-01-01"), // invalid expanded year, too long
+        (12345, 1, 1, false, "++12345-01-01"), // invalid expanded year, repeated sign
+        (12345, 1, 1, false, "+-12345-01-01"), // invalid expanded year, negative sign
+        (1970, 1, 1, false, "+01970-01-01"), // invalid expanded year, leading zero
+        (10000, 1, 1, false, "+1000a-01-01"), // invalid expanded year, not integer
+        (12345, 6, 15, false, "+12345é06-15"), // invalid expanded date, non-ascii
+        (2023, 6, 15, false, "2023é6-15"),   // invalid date, non-ascii
     ];
-    let mut buf = [0; UTCDate::ISO_DATE_LEN];
+    let mut buf = [0; UTCDate::MAX_ISO_DATE_LEN];
 
     for (year, month, day, case_is_valid, iso_date) in test_cases {
         match UTCDate::try_from_iso_date(iso_date) {
@@ -92,7 +763,10 @@ fn test_date_iso_conversions() -> Result<(), UTCError> {
                 assert_eq!(iso_date, date_from_comp.as_iso_date());
                 let written = date_from_comp.write_iso_date(&mut buf)?;
                 assert_eq!(iso_date.as_bytes(), &buf[..written]);
+                assert_eq!(date_from_comp.to_iso_array(), iso_date);
+                assert_eq!(date_from_comp.to_iso_array().as_str(), iso_date);
                 assert_eq!(iso_date, core::str::from_utf8(&buf[..written]).unwrap());
+                assert_eq!(written, date_from_comp.iso_date_len());
                 // test invalid buf len
                 let mut buf = [0; 1];
                 assert!(date_from_comp.write_iso_date(&mut buf).is_err());
@@ -103,6 +777,73 @@ fn test_date_iso_conversions() -> Result<(), UTCError> {
         }
     }
 
+    // test stack allocated iso conversions
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    let iso = date.to_iso_array();
+    assert_eq!(iso, "2023-06-15");
+    assert_eq!(iso.len(), UTCDate::ISO_DATE_LEN);
+    assert_eq!(UTCDate::try_from_iso_date(&iso)?, date);
+    assert_eq!(UTCDate::MAX.to_iso_array(), "+584554051223-11-09");
+    assert_eq!(UTCDate::MAX.to_iso_array().len(), UTCDate::MAX_ISO_DATE_LEN);
+    // test out of range years raise domain errors rather than parse errors
+    assert!(matches!(
+        UTCDate::try_from_iso_date("+999999999999-01-01"),
+        Err(UTCDateError::YearOutOfRange(999_999_999_999))
+    ));
+    // years overflowing a u64 are mapped to the maximum year
+    let long_year = "9".repeat(25);
+    assert!(matches!(
+        UTCDate::try_from_english(&format!("15 June {long_year}")),
+        Err(UTCDateError::YearOutOfRange(u64::MAX))
+    ));
+
+    // test non-canonical and non-ascii strs raise format errors
+    for iso in ["++12345-01-01", "+01970-01-01", "+12345é06-15", "2023é6-15"] {
+        assert!(matches!(
+            UTCDate::try_from_iso_date(iso),
+            Err(UTCDateError::InvalidFormat)
+        ));
+    }
+    assert!(UTCDateError::InvalidStrLen(11)
+        .to_string()
+        .contains("12 to 19 for expanded years"));
+
+    // test parsing with surrounding whitespace
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    for padded in [
+        "2023-06-15",
+        " 2023-06-15",
+        "2023-06-15 ",
+        "  2023-06-15  ",
+        "\t2023-06-15\t",
+        " \t2023-06-15\r\n",
+    ] {
+        assert_eq!(UTCDate::try_from_iso_date_trimmed(padded)?, date);
+    }
+    assert_eq!(
+        UTCDate::try_from_iso_date_trimmed(" +584554051223-11-09 ")?,
+        UTCDate::MAX
+    );
+    assert!(UTCDate::try_from_iso_date(" 2023-06-15 ").is_err());
+    assert!(UTCDate::try_from_iso_date_trimmed("2023 -06-15").is_err());
+    assert!(UTCDate::try_from_iso_date_trimmed("   ").is_err());
+
+    // test str comparisons
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    assert_eq!(date, "2023-06-15");
+    assert_eq!(date, *"2023-06-15");
+    assert_ne!(date, "2023-06-16");
+    assert_ne!(date, "2023-06-15T00:00:00Z");
+    assert_ne!(date, "");
+    assert_eq!(UTCDate::MAX, "+584554051223-11-09");
+
+    // test common era string
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        UTCDate::try_from_iso_date("2023-06-15")?.common_era_string(),
+        "2023-06-15 CE"
+    );
+
     // test transform from system time
     #[cfg(feature = "std")]
     {
@@ -192,10 +933,57 @@ fn test_date_transformations() -> Result<(), UTCError> {
     Ok(())
 }
 
+#[test]
+fn test_date_ordering() -> Result<(), UTCError> {
+    // january and february follow the preceding december,
+    // and march of the prior year
+    let march = UTCDate::try_from_components(2023, 3, 1)?;
+    let december = UTCDate::try_from_components(2023, 12, 31)?;
+    let january = UTCDate::try_from_components(2024, 1, 1)?;
+    let february = UTCDate::try_from_components(2024, 2, 29)?;
+    assert!(march < december);
+    assert!(december < january);
+    assert!(december < february);
+    assert!(march < january);
+    assert!(march < february);
+    assert!(january < february);
+    assert_eq!(january.cmp(&january), core::cmp::Ordering::Equal);
+    assert_eq!(december.max(january), january);
+    // ordering agrees with days since the epoch
+    let mut dates = [
+        february,
+        january,
+        march,
+        december,
+        UTCDate::MAX,
+        UTCDate::MIN,
+    ];
+    dates.sort();
+    assert_eq!(
+        dates,
+        [
+            UTCDate::MIN,
+            march,
+            december,
+            january,
+            february,
+            UTCDate::MAX
+        ]
+    );
+    for pair in dates.windows(2) {
+        assert!(pair[0].as_day() < pair[1].as_day());
+    }
+    // datetimes are ordered by their dates
+    let late_december = UTCDatetime::from_components(december, UTCTimeOfDay::MAX);
+    let early_january = UTCDatetime::from_components(january, UTCTimeOfDay::ZERO);
+    assert!(late_december < early_january);
+    Ok(())
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_date_serde() {
     let date = UTCDate::from_day(UTCDay::try_from_u64(19959).unwrap());
-    let v = serde_json::to_value(&date).unwrap();
-    assert_eq!(date, serde_json::from_value(v).unwrap())
+    let v = serde_json::to_value(date).unwrap();
+    assert_eq!(date, serde_json::from_value::<UTCDate>(v).unwrap())
 }
@@ -1,9 +1,23 @@
+use core::time::Duration;
+
 use utc_dt::{
-    date::UTCDate,
-    time::{UTCDay, UTCTimeOfDay, UTCTimestamp, UTCTransformations},
-    UTCDatetime, UTCError,
+    date::{Days, Months, UTCDate, Years},
+    time::{UTCDay, UTCTimeOfDay, UTCTimeOfDayError, UTCTimestamp, UTCTransformations, Weekday},
+    UTCDatetime, UTCDatetimeError, UTCDatetimeFormat, UTCError,
 };
 
+#[test]
+fn test_datetime_public_path() {
+    // the datetime, its components and errors are exported from stable public paths
+    let datetime: utc_dt::UTCDatetime = utc_dt::UTCDatetime::from_components(
+        utc_dt::date::UTCDate::MIN,
+        utc_dt::time::UTCTimeOfDay::ZERO,
+    );
+    assert_eq!(datetime, UTCDatetime::MIN);
+    let error: utc_dt::UTCDatetimeError = utc_dt::date::UTCDateError::YearOutOfRange(0).into();
+    assert!(matches!(error, UTCDatetimeError::UTCDate(_)));
+}
+
 #[test]
 fn test_datetime_from_raw_components() -> Result<(), UTCError> {
     let test_cases = [
@@ -12,9 +26,9 @@ fn test_datetime_from_raw_components() -> Result<(), UTCError> {
             2023,
             6,
             14,
-            09,
+            9,
             20,
-            09,
+            9,
             648_000_000,
             33_609_648_000_000,
             UTCDay::try_from_u64(19522)?,
@@ -29,7 +43,37 @@ fn test_datetime_from_raw_components() -> Result<(), UTCError> {
         let datetime = UTCDatetime::from_components(date, tod);
         assert_eq!(datetime.as_date().as_day(), expected_day);
         assert_eq!(datetime.as_tod().as_nanos(), expected_tod_ns);
+        // broken-down components round-trip
+        let ymd_hms_nanos = (year, month, day, hrs, mins, secs, subsec_ns);
+        assert_eq!(datetime.as_ymd_hms_nanos(), ymd_hms_nanos);
+        assert_eq!(
+            UTCDatetime::try_from_ymd_hms_nanos(year, month, day, hrs, mins, secs, subsec_ns)?,
+            datetime
+        );
     }
+    // datetime at the start of a date
+    let date = UTCDate::try_from_components(2023, 6, 15)?;
+    let datetime = UTCDatetime::from_date(date);
+    assert_eq!(datetime.as_components(), (date, UTCTimeOfDay::ZERO));
+    assert_eq!(UTCDatetime::from(date), datetime);
+    assert_eq!(datetime, datetime.start_of_day());
+    assert_eq!(UTCDatetime::from_date(UTCDate::MIN), UTCDatetime::MIN);
+    assert_eq!(
+        UTCDatetime::from_date(UTCDate::MAX).as_tod(),
+        UTCTimeOfDay::ZERO
+    );
+    assert_eq!(
+        UTCDatetime::MAX.as_ymd_hms_nanos(),
+        (UTCDate::MAX_YEAR, 11, 9, 7, 0, 15, 999_999_999)
+    );
+    assert!(matches!(
+        UTCDatetime::try_from_ymd_hms_nanos(2023, 2, 29, 0, 0, 0, 0),
+        Err(UTCDatetimeError::UTCDate(_))
+    ));
+    assert!(matches!(
+        UTCDatetime::try_from_ymd_hms_nanos(2023, 6, 15, 24, 0, 0, 0),
+        Err(UTCDatetimeError::UTCTimeOfDay(_))
+    ));
 
     // test display & debug
     #[cfg(feature = "std")]
@@ -89,6 +133,25 @@ fn test_datetime_from_raw_components() -> Result<(), UTCError> {
         UTCDatetime::from_timestamp(UTCTimestamp::ZERO),
         UTCDatetime::MIN
     );
+    // test lossless u128 nanos conversions
+    let max_nanos = UTCDatetime::MAX.as_nanos_u128();
+    assert_eq!(max_nanos, UTCTimestamp::MAX.as_nanos());
+    assert_eq!(
+        UTCDatetime::try_from_nanos_u128(max_nanos)?,
+        UTCDatetime::MAX
+    );
+    let near_max = UTCDatetime::try_from_nanos_u128(max_nanos - 1)?;
+    assert!(near_max < UTCDatetime::MAX);
+    assert_eq!(near_max.as_nanos_u128(), max_nanos - 1);
+    assert_eq!(near_max.as_tod().as_subsec_ns(), 999_999_998);
+    assert!(UTCDatetime::try_from_nanos_u128(max_nanos + 1).is_err());
+    assert!(UTCDatetime::try_from_nanos_u128(u128::MAX).is_err());
+    assert_eq!(UTCDatetime::try_from_nanos_u128(0)?, UTCDatetime::MIN);
+    assert_eq!(
+        UTCDatetime::try_from_nanos_u128(datetime.as_nanos_u128())?,
+        datetime
+    );
+    assert_eq!(datetime.as_nanos_u128(), datetime.as_nanos());
 
     Ok(())
 }
@@ -109,6 +172,45 @@ fn test_datetime_iso_conversions() -> Result<(), UTCError> {
             "2023-06-14T09:20:09.648Z",
         ), // wed, 09:20:09.648
     ];
+    // test parsed precision is reported for re-emission
+    for (iso, expected_precision, expected_iso) in [
+        ("2023-06-15T10:18:08Z", 0, "2023-06-15T10:18:08Z"),
+        ("2023-06-15T10:18:08.Z", 0, "2023-06-15T10:18:08Z"),
+        ("2023-06-15T10:18:08.9Z", 1, "2023-06-15T10:18:08.9Z"),
+        ("2023-06-15T10:18:08.903Z", 3, "2023-06-15T10:18:08.903Z"),
+        (
+            "2023-06-15T10:18:08.900000Z",
+            6,
+            "2023-06-15T10:18:08.900000Z",
+        ),
+        (
+            "2023-06-15T10:18:08.903123456Z",
+            9,
+            "2023-06-15T10:18:08.903123456Z",
+        ),
+        (
+            "+10000-01-01T00:00:00.000Z",
+            3,
+            "+10000-01-01T00:00:00.000Z",
+        ),
+    ] {
+        let (datetime, precision) = UTCDatetime::try_from_iso_datetime_with_precision(iso)?;
+        assert_eq!(precision, expected_precision);
+        assert_eq!(datetime, UTCDatetime::try_from_iso_datetime(iso)?);
+        let mut buf = [0; UTCDatetime::MAX_ISO_DATETIME_LEN];
+        let written = datetime.write_iso_datetime(&mut buf, precision)?;
+        assert_eq!(&buf[..written], expected_iso.as_bytes());
+    }
+    assert!(UTCDatetime::try_from_iso_datetime_with_precision("2023-06-15T10:18:08.Z0").is_err());
+    // test worst-case iso length constant, evaluated at compile time
+    const _: () = assert!(
+        UTCDatetime::MAX_ISO_DATETIME_LEN
+            == UTCDatetime::iso_datetime_len(9) + UTCDate::MAX_ISO_DATE_LEN - UTCDate::ISO_DATE_LEN
+    );
+    assert_eq!(
+        UTCDatetime::MAX.write_iso_datetime(&mut [0; UTCDatetime::MAX_ISO_DATETIME_LEN], 9)?,
+        UTCDatetime::MAX_ISO_DATETIME_LEN
+    );
     let mut buf = [0; UTCDatetime::iso_datetime_len(9)];
 
     // run iso conversion test cases
@@ -138,11 +240,85 @@ fn test_datetime_iso_conversions() -> Result<(), UTCError> {
         }
     }
 
+    // test non-ascii expanded years are rejected without panicking
+    assert!(UTCDatetime::try_from_iso_datetime("+12345é06-15T00:00:00Z").is_err());
+    assert!(UTCDatetime::try_from_iso_datetime("++12345-06-15T00:00:00Z").is_err());
+    // test expanded years
+    let mut buf = [0; UTCDatetime::MAX_ISO_DATETIME_LEN];
+    for (iso_datetime, precision) in [
+        ("+10000-01-01T00:00:00Z", 0),
+        ("+584554051223-11-09T07:00:15.999999999Z", 9),
+    ] {
+        let datetime = UTCDatetime::try_from_iso_datetime(iso_datetime)?;
+        let written = datetime.write_iso_datetime(&mut buf, precision)?;
+        assert_eq!(iso_datetime.as_bytes(), &buf[..written]);
+        #[cfg(feature = "alloc")]
+        assert_eq!(datetime.as_iso_datetime(precision), iso_datetime);
+    }
+    assert_eq!(
+        UTCDatetime::try_from_iso_datetime("+584554051223-11-09T07:00:15.999999999Z")?,
+        UTCDatetime::MAX
+    );
+    let mut buf = [0; UTCDatetime::iso_datetime_len(0)];
+    assert!(UTCDatetime::MAX.write_iso_datetime(&mut buf, 0).is_err());
+    assert!(UTCDatetime::try_from_iso_datetime("+2023-06-15T00:00:00Z").is_err());
+
+    // test parse failure positions
+    for (iso, expected_offset) in [
+        ("2023-06-15Tx0:18:08Z", 11),
+        ("2023-06-15T10:18:x8Z", 17),
+        ("+10000-06-15T10:18:x8Z", 19),
+    ] {
+        match UTCDatetime::try_from_iso_datetime(iso) {
+            Err(UTCDatetimeError::UTCTimeOfDay(UTCTimeOfDayError::InvalidComponent(
+                _,
+                offset,
+                substr,
+                _,
+            ))) => {
+                assert_eq!(offset, expected_offset);
+                assert!(substr.contains('x'));
+                assert_eq!(iso.as_bytes()[offset], b'x');
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    // test str comparisons
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    assert_eq!(datetime, "2023-06-15T10:18:08.903000000Z");
+    assert_eq!(datetime, *"2023-06-15T10:18:08.903000000Z");
+    assert_ne!(datetime, "2023-06-15T10:18:08.903Z");
+    assert_ne!(datetime, "2023-06-15");
+    assert_eq!(UTCDatetime::MAX, "+584554051223-11-09T07:00:15.999999999Z");
+    #[cfg(feature = "alloc")]
+    assert_eq!(datetime, datetime.to_string().as_str());
+
     // test invalid iso dates
     assert!(UTCDatetime::try_from_iso_datetime("197a-01-01T00:00:00Z").is_err());
     assert!(UTCDatetime::try_from_iso_datetime("1970-01-01T00:a0:00Z").is_err());
     assert!(UTCDatetime::try_from_iso_datetime("1970-01-01T00:a0").is_err());
 
+    // test prefix parsing
+    let (parsed, rest) = UTCDatetime::parse_prefix("2023-06-15T10:18:08.903Z rest of line")?;
+    assert_eq!(parsed, datetime);
+    assert_eq!(rest, " rest of line");
+    for (s, expected_rest) in [
+        ("2023-06-15T10:18:08ZZ", "Z"),
+        ("2023-06-15T10:18:08.903456789Z", ""),
+        ("+10000-06-15T10:18:08Z\n", "\n"),
+    ] {
+        let (parsed, rest) = UTCDatetime::parse_prefix(s)?;
+        assert_eq!(rest, expected_rest);
+        assert_eq!(
+            parsed,
+            UTCDatetime::try_from_iso_datetime(&s[..s.len() - rest.len()])?
+        );
+    }
+    assert!(UTCDatetime::parse_prefix("2023-06-15T10:18:08 rest of line").is_err());
+    assert!(UTCDatetime::parse_prefix("rest of line 2023-06-15T10:18:08Z").is_err());
+    assert!(UTCDatetime::parse_prefix("").is_err());
+
     // test display & debug
     #[cfg(feature = "std")]
     {
@@ -152,10 +328,677 @@ fn test_datetime_iso_conversions() -> Result<(), UTCError> {
     Ok(())
 }
 
+#[test]
+fn test_datetime_rfc3339_output() -> Result<(), UTCError> {
+    let test_cases = [
+        ("2023-06-15T10:18:08Z", 0, "2023-06-15T10:18:08Z"),
+        ("2023-06-15T10:18:08.000Z", 0, "2023-06-15T10:18:08Z"),
+        ("2023-06-15T10:18:08.9Z", 3, "2023-06-15T10:18:08.900Z"),
+        ("2023-06-15T10:18:08.903Z", 3, "2023-06-15T10:18:08.903Z"),
+        (
+            "2023-06-15T10:18:08.903100Z",
+            6,
+            "2023-06-15T10:18:08.903100Z",
+        ),
+        (
+            "2023-06-15T10:18:08.000001Z",
+            6,
+            "2023-06-15T10:18:08.000001Z",
+        ),
+        (
+            "2023-06-15T10:18:08.903123456Z",
+            9,
+            "2023-06-15T10:18:08.903123456Z",
+        ),
+        (
+            "2023-06-15T10:18:08.000000001Z",
+            9,
+            "2023-06-15T10:18:08.000000001Z",
+        ),
+        ("+10000-01-01T00:00:00.5Z", 3, "+10000-01-01T00:00:00.500Z"),
+    ];
+    let mut buf = [0; UTCDatetime::iso_datetime_len(9) + 9];
+    for (iso, expected_precision, expected) in test_cases {
+        let datetime = UTCDatetime::try_from_iso_datetime(iso)?;
+        assert_eq!(datetime.rfc3339_precision(), expected_precision);
+        #[cfg(feature = "alloc")]
+        assert_eq!(datetime.to_rfc3339(), expected);
+        let written = datetime.write_rfc3339(&mut buf)?;
+        assert_eq!(&buf[..written], expected.as_bytes());
+        // round-trips via parsing
+        assert_eq!(UTCDatetime::parse_any(expected).unwrap(), datetime);
+    }
+    // test insufficient buffer
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    let mut buf = [0; UTCDatetime::iso_datetime_len(3) - 1];
+    assert!(matches!(
+        datetime.write_rfc3339(&mut buf),
+        Err(UTCDatetimeError::InsufficientStrLen(23, 24))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_datetime_parse_any() -> Result<(), UTCError> {
+    let expected = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    let expected_secs = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08Z")?;
+    let test_cases = [
+        ("2023-06-15T10:18:08.903Z", expected), // ISO 8601 extended
+        ("20230615T101808.903Z", expected),     // ISO 8601 basic
+        ("20230615T101808Z", expected_secs),    // ISO 8601 basic, no subseconds
+        ("2023-06-15T10:18:08.903+00:00", expected), // RFC 3339, zero offset
+        ("2023-06-15T20:18:08.903+10:00", expected), // RFC 3339, positive offset
+        ("2023-06-15T04:48:08.903-05:30", expected), // RFC 3339, negative offset
+        ("2023-06-15t10:18:08.903z", expected), // RFC 3339, lowercase
+        ("Thu, 15 Jun 2023 10:18:08 +0000", expected_secs), // RFC 2822
+        ("15 Jun 2023 11:18:08 +0100", expected_secs), // RFC 2822, no weekday
+        ("Thu, 15 Jun 2023 10:18:08 GMT", expected_secs), // RFC 2822, named zone
+    ];
+    for (s, expected) in test_cases {
+        assert_eq!(UTCDatetime::parse_any(s)?, expected);
+    }
+    // RFC 2822 without seconds
+    assert_eq!(
+        UTCDatetime::parse_any("Thu, 15 Jun 2023 10:18 +0000")?,
+        UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:00Z")?
+    );
+
+    // test unparseable strs
+    let invalid_cases = [
+        "not a datetime",
+        "",
+        "Fri, 15 Jun 2023 10:18:08 +0000", // weekday mismatch
+        "2023-06-15T10:18:08.903+24:00",   // offset out of range
+        "1970-01-01T00:00:00+01:00",       // normalized before epoch
+        "ééééééééééééééééééééééé",         // non-ascii
+    ];
+    for s in invalid_cases {
+        let err = UTCDatetime::parse_any(s).unwrap_err();
+        let attempted: Vec<UTCDatetimeFormat> = err.attempts().map(|(f, _)| f).collect();
+        assert_eq!(attempted, UTCDatetimeFormat::ALL);
+        println!("{err}");
+    }
+    // rfc 2822 years are four digits, so overflowing years are format errors
+    let long_year = "9".repeat(25);
+    let err = UTCDatetime::parse_any(&format!("15 Jun {long_year} 10:18:08 +0000")).unwrap_err();
+    assert!(err.attempts().any(|(f, e)| f == UTCDatetimeFormat::Rfc2822
+        && matches!(
+            e,
+            UTCDatetimeError::InvalidFormat(UTCDatetimeFormat::Rfc2822)
+        )));
+    let err = UTCDatetime::parse_any("1970-01-01T00:00:00+01:00").unwrap_err();
+    assert!(err
+        .attempts()
+        .any(|(_, e)| matches!(e, UTCDatetimeError::OffsetOutOfRange(3600))));
+    Ok(())
+}
+
+#[test]
+fn test_datetime_day_and_week_bounds() -> Result<(), UTCError> {
+    // thursday
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    assert_eq!(
+        datetime.start_of_day(),
+        UTCDatetime::try_from_iso_datetime("2023-06-15T00:00:00Z")?
+    );
+    assert_eq!(
+        datetime.end_of_day(),
+        UTCDatetime::try_from_iso_datetime("2023-06-15T23:59:59.999999999Z")?
+    );
+    let test_cases = [
+        (
+            Weekday::Monday,
+            "2023-06-12T00:00:00Z",
+            "2023-06-18T23:59:59.999999999Z",
+        ),
+        (
+            Weekday::Sunday,
+            "2023-06-11T00:00:00Z",
+            "2023-06-17T23:59:59.999999999Z",
+        ),
+        (
+            Weekday::Thursday,
+            "2023-06-15T00:00:00Z",
+            "2023-06-21T23:59:59.999999999Z",
+        ),
+        (
+            Weekday::Friday,
+            "2023-06-09T00:00:00Z",
+            "2023-06-15T23:59:59.999999999Z",
+        ),
+    ];
+    for (first_day, start, end) in test_cases {
+        let start = UTCDatetime::try_from_iso_datetime(start)?;
+        let end = UTCDatetime::try_from_iso_datetime(end)?;
+        assert_eq!(datetime.start_of_week(first_day), start);
+        assert_eq!(datetime.end_of_week(first_day), end);
+        assert_eq!(start.as_date().weekday(), first_day);
+        // bounds are within the same week
+        assert_eq!(start.start_of_week(first_day), start);
+        assert_eq!(end.start_of_week(first_day), start);
+        assert_eq!(end.end_of_week(first_day), end);
+    }
+    // test saturation at limits
+    assert_eq!(
+        UTCDatetime::MIN.start_of_week(Weekday::Monday),
+        UTCDatetime::MIN
+    );
+    assert_eq!(
+        UTCDatetime::MAX.end_of_week(Weekday::Monday),
+        UTCDatetime::MAX
+    );
+    assert_eq!(UTCDatetime::MAX.end_of_day(), UTCDatetime::MAX);
+    // test same day comparisons
+    let morning = UTCDatetime::try_from_iso_datetime("2023-06-15T00:00:00Z")?;
+    let evening = UTCDatetime::try_from_iso_datetime("2023-06-15T23:59:59.999Z")?;
+    let next_morning = UTCDatetime::try_from_iso_datetime("2023-06-16T00:00:00Z")?;
+    let next_month = UTCDatetime::try_from_iso_datetime("2023-07-15T10:18:08Z")?;
+    assert!(morning.is_same_day(&evening));
+    assert!(evening.is_same_day(&morning));
+    assert!(!evening.is_same_day(&next_morning));
+    assert!(!datetime.is_same_day(&next_month));
+    assert!(UTCDatetime::MAX.is_same_day(&UTCDatetime::MAX.start_of_day()));
+    Ok(())
+}
+
+#[test]
+fn test_datetime_stepping() -> Result<(), UTCError> {
+    let start = UTCDatetime::try_from_iso_datetime("2023-06-15T10:00:00Z")?;
+    let five_mins = Duration::from_secs(5 * 60);
+    let expected = [
+        "2023-06-15T10:00:00Z",
+        "2023-06-15T10:05:00Z",
+        "2023-06-15T10:10:00Z",
+        "2023-06-15T10:15:00Z",
+    ];
+    assert_eq!(start.iter_step(five_mins, 4).count(), 4);
+    for (datetime, iso) in start.iter_step(five_mins, 4).zip(expected) {
+        assert_eq!(datetime, UTCDatetime::try_from_iso_datetime(iso)?);
+    }
+    assert_eq!(start.iter_step(five_mins, 0).count(), 0);
+    // stepping until an exclusive end
+    let end = UTCDatetime::try_from_iso_datetime("2023-06-15T10:15:00Z")?;
+    assert_eq!(start.step_until(end, five_mins).count(), 3);
+    assert!(start
+        .step_until(end, five_mins)
+        .zip(start.iter_step(five_mins, 3))
+        .all(|(a, b)| a == b));
+    assert_eq!(end.step_until(start, five_mins).count(), 0);
+    assert_eq!(start.step_until(end, Duration::ZERO).count(), 1);
+    // test approximate equality
+    let tolerance = Duration::from_millis(1);
+    let just_within = UTCDatetime::try_from_iso_datetime("2023-06-15T10:00:00.001Z")?;
+    let just_outside = UTCDatetime::try_from_iso_datetime("2023-06-15T10:00:00.001000001Z")?;
+    assert!(start.approx_eq(start, Duration::ZERO));
+    assert!(start.approx_eq(just_within, tolerance));
+    assert!(just_within.approx_eq(start, tolerance));
+    assert!(!start.approx_eq(just_outside, tolerance));
+    assert!(!just_outside.approx_eq(start, tolerance));
+    // across a day boundary
+    let before_midnight = UTCDatetime::try_from_iso_datetime("2023-06-15T23:59:59.9995Z")?;
+    let after_midnight = UTCDatetime::try_from_iso_datetime("2023-06-16T00:00:00.0005Z")?;
+    assert!(before_midnight.approx_eq(after_midnight, tolerance));
+    // test saturation near the maximum
+    let near_max = UTCDatetime::try_from_nanos_u128(UTCDatetime::MAX.as_nanos_u128() - 1)
+        .expect("within range");
+    let stepped: Vec<UTCDatetime> = near_max.iter_step(Duration::from_secs(1), 3).collect();
+    assert_eq!(stepped, [near_max, UTCDatetime::MAX, UTCDatetime::MAX]);
+    assert_eq!(
+        near_max
+            .step_until(UTCDatetime::MAX, Duration::from_nanos(1))
+            .collect::<Vec<_>>(),
+        [near_max]
+    );
+    assert_eq!(
+        near_max
+            .step_until(UTCDatetime::MAX, Duration::MAX)
+            .collect::<Vec<_>>(),
+        [near_max]
+    );
+    Ok(())
+}
+
+/// Counts bytes written, without storing them
+struct ByteCounter(usize);
+
+impl core::fmt::Write for ByteCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_datetime_write_to() -> Result<(), UTCError> {
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903456789Z")?;
+    for (precision, expected) in [
+        (0, "2023-06-15T10:18:08Z"),
+        (3, "2023-06-15T10:18:08.903Z"),
+        (9, "2023-06-15T10:18:08.903456789Z"),
+        (12, "2023-06-15T10:18:08.903456789Z"),
+    ] {
+        let mut s = String::new();
+        datetime.write_to(&mut s, precision).unwrap();
+        assert_eq!(s, expected);
+        let mut counter = ByteCounter(0);
+        datetime.write_to(&mut counter, precision).unwrap();
+        assert_eq!(counter.0, UTCDatetime::iso_datetime_len(precision));
+        // consistent with the buffer api
+        let mut buf = [0; 64];
+        let written = datetime.write_iso_datetime(&mut buf, precision)?;
+        assert_eq!(&buf[..written], expected.as_bytes());
+    }
+    // appends to existing content, with expanded years
+    let mut s = String::from("max: ");
+    UTCDatetime::MAX.write_to(&mut s, 0).unwrap();
+    assert_eq!(s, "max: +584554051223-11-09T07:00:15Z");
+    Ok(())
+}
+
+#[test]
+fn test_datetime_stable_hash() -> Result<(), UTCError> {
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    assert_eq!(datetime.fnv1a_hash(), 7057420398610431472);
+    assert_eq!(datetime.fnv1a_hash(), datetime.as_timestamp().fnv1a_hash());
+    assert_eq!(UTCDatetime::MIN.fnv1a_hash(), 9808874869469701221);
+    assert_ne!(
+        datetime.fnv1a_hash(),
+        datetime.truncate_to_secs().fnv1a_hash()
+    );
+    // dates hash as days
+    let date = datetime.as_date();
+    assert_eq!(date.fnv1a_hash(), 8193008030969051050);
+    assert_eq!(date.fnv1a_hash(), date.as_day().fnv1a_hash());
+    assert_eq!(UTCDate::MIN.fnv1a_hash(), 12161962213042174405);
+    Ok(())
+}
+
+#[test]
+fn test_datetime_truncation() -> Result<(), UTCError> {
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903456789Z")?;
+    let truncated = datetime.truncate_to_secs();
+    assert_eq!(truncated.as_components().0, datetime.as_components().0);
+    assert_eq!(truncated.as_tod().as_subsec_ns(), 0);
+    assert_eq!(truncated.as_tod().as_hhmmss(), (10, 18, 8));
+    assert_eq!(
+        truncated,
+        UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08Z")?
+    );
+    assert_eq!(
+        datetime.truncate_to_millis(),
+        UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?
+    );
+    assert_eq!(
+        datetime.truncate_to_micros(),
+        UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903456Z")?
+    );
+    // truncation is idempotent
+    assert_eq!(truncated.truncate_to_secs(), truncated);
+    assert_eq!(UTCDatetime::MIN.truncate_to_secs(), UTCDatetime::MIN);
+    assert_eq!(
+        UTCDatetime::MAX
+            .truncate_to_micros()
+            .as_tod()
+            .as_subsec_ns()
+            % 1000,
+        0
+    );
+    // current time truncation
+    #[cfg(feature = "std")]
+    {
+        let now = UTCDatetime::try_now_secs().unwrap();
+        assert_eq!(now.as_tod().as_subsec_ns(), 0);
+        assert_eq!(
+            UTCDatetime::try_now_millis()
+                .unwrap()
+                .as_tod()
+                .as_subsec_ns()
+                % 1_000_000,
+            0
+        );
+        assert_eq!(
+            UTCDatetime::try_now_micros()
+                .unwrap()
+                .as_tod()
+                .as_subsec_ns()
+                % 1_000,
+            0
+        );
+        assert!(UTCDatetime::try_now_millis().unwrap() >= now);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_datetime_calendar_arithmetic() -> Result<(), UTCError> {
+    // durations between datetimes
+    let earlier = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    let later = UTCDatetime::try_from_iso_datetime("2023-06-16T10:18:09Z")?;
+    assert_eq!(
+        earlier.duration_until(later),
+        Some(Duration::new(86_400, 97_000_000))
+    );
+    assert_eq!(earlier.duration_until(earlier), Some(Duration::ZERO));
+    assert_eq!(later.duration_until(earlier), None);
+    assert_eq!(
+        UTCDatetime::MIN.duration_until(UTCDatetime::MAX),
+        Some(UTCTimestamp::MAX.as_duration())
+    );
+    #[cfg(feature = "std")]
+    {
+        assert!(earlier.duration_until_now().unwrap() > Duration::ZERO);
+        assert!(earlier.duration_until_now() > later.duration_until_now());
+        assert_eq!(UTCDatetime::MAX.duration_until_now(), None);
+    }
+    // month clamping preserves the time of day
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-01-31T10:00:00.123Z")?;
+    assert_eq!(
+        datetime.checked_add_months(1),
+        Some(UTCDatetime::try_from_iso_datetime(
+            "2023-02-28T10:00:00.123Z"
+        )?)
+    );
+    assert_eq!(
+        datetime.checked_add_months(-2),
+        Some(UTCDatetime::try_from_iso_datetime(
+            "2022-11-30T10:00:00.123Z"
+        )?)
+    );
+    assert_eq!(datetime.checked_add_months(0), Some(datetime));
+    assert_eq!(
+        datetime.checked_add_years(1),
+        Some(UTCDatetime::try_from_iso_datetime(
+            "2024-01-31T10:00:00.123Z"
+        )?)
+    );
+    let leap_day = UTCDatetime::try_from_iso_datetime("2024-02-29T23:59:59Z")?;
+    assert_eq!(
+        leap_day.checked_add_years(-1),
+        Some(UTCDatetime::try_from_iso_datetime("2023-02-28T23:59:59Z")?)
+    );
+    // bounds
+    assert_eq!(UTCDatetime::MIN.checked_add_months(-1), None);
+    assert_eq!(UTCDatetime::MAX.checked_add_months(1), None);
+    assert_eq!(UTCDatetime::MIN.checked_add_months(i64::MIN), None);
+    assert_eq!(UTCDatetime::MIN.checked_add_years(i64::MAX), None);
+    // date within range, but time of day exceeding the maximum datetime
+    let (max_year, _, _) = UTCDatetime::MAX.as_date().as_components();
+    let late = UTCDatetime::from_components(
+        UTCDate::try_from_components(max_year, 10, 9)?,
+        UTCTimeOfDay::MAX,
+    );
+    assert_eq!(late.checked_add_months(1), None);
+    assert_eq!(
+        late.checked_add_months(-1),
+        Some(UTCDatetime::from_components(
+            UTCDate::try_from_components(max_year, 9, 9)?,
+            UTCTimeOfDay::MAX
+        ))
+    );
+    assert_eq!(late.checked_add_days(31), None);
+    // typed calendar arithmetic
+    assert_eq!(
+        datetime.checked_add_days(-31),
+        Some(UTCDatetime::try_from_iso_datetime(
+            "2022-12-31T10:00:00.123Z"
+        )?)
+    );
+    assert_eq!(UTCDatetime::MIN.checked_add_days(-1), None);
+    assert_eq!(
+        datetime + Months(1) + Days(3),
+        UTCDatetime::try_from_iso_datetime("2023-03-03T10:00:00.123Z")?
+    );
+    assert_eq!(
+        datetime + Years(1) - Months(1),
+        UTCDatetime::try_from_iso_datetime("2023-12-31T10:00:00.123Z")?
+    );
+    let mut shifted = leap_day;
+    shifted -= Years(4);
+    shifted += Days(1);
+    assert_eq!(
+        shifted,
+        UTCDatetime::try_from_iso_datetime("2020-03-01T23:59:59Z")?
+    );
+    shifted -= Days(1);
+    shifted += Months(12);
+    assert_eq!(
+        shifted,
+        UTCDatetime::try_from_iso_datetime("2021-02-28T23:59:59Z")?
+    );
+    assert!(std::panic::catch_unwind(|| late + Days(31)).is_err());
+    assert!(std::panic::catch_unwind(|| UTCDatetime::MIN - Months(1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_datetime_dos_conversions() -> Result<(), UTCError> {
+    let test_cases = [
+        (0x56CF, 0x5244, "2023-06-15T10:18:08Z"),
+        (0x0021, 0x0000, "1980-01-01T00:00:00Z"), // minimum
+        (0xFF9F, 0xBF7D, "2107-12-31T23:59:58Z"), // maximum
+    ];
+    for (dos_date, dos_time, iso_datetime) in test_cases {
+        let datetime = UTCDatetime::try_from_iso_datetime(iso_datetime)?;
+        assert_eq!(
+            UTCDatetime::try_from_dos_datetime(dos_date, dos_time)?,
+            datetime
+        );
+        assert_eq!(datetime.to_dos_datetime(), Some((dos_date, dos_time)));
+    }
+    // test truncation to 2 second resolution
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:09.903Z")?;
+    assert_eq!(datetime.to_dos_datetime(), Some((0x56CF, 0x5244)));
+    // test unrepresentable datetimes
+    assert_eq!(UTCDatetime::MIN.to_dos_datetime(), None);
+    assert_eq!(
+        UTCDatetime::try_from_iso_datetime("2108-01-01T00:00:00Z")?.to_dos_datetime(),
+        None
+    );
+    // test invalid dos datetimes
+    assert!(UTCDatetime::try_from_dos_datetime(0x56C0, 0x5244).is_err()); // day 0
+    assert!(UTCDatetime::try_from_dos_datetime(0x57AF, 0x5244).is_err()); // month 13
+    assert!(UTCDatetime::try_from_dos_datetime(0x56CF, 0xC000).is_err()); // hour 24
+    assert!(UTCDatetime::try_from_dos_datetime(0x56CF, 0x0780).is_err()); // minute 60
+    assert!(UTCDatetime::try_from_dos_datetime(0x56CF, 0x001E).is_err()); // second 60
+    Ok(())
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_datetime_serde() {
     let datetime = UTCDatetime::from_secs(1724493234);
-    let v = serde_json::to_value(&datetime).unwrap();
-    assert_eq!(datetime, serde_json::from_value(v).unwrap());
+    let v = serde_json::to_value(datetime).unwrap();
+    assert_eq!(datetime, serde_json::from_value::<UTCDatetime>(v).unwrap());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_datetime_serde_iso() -> Result<(), UTCError> {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "utc_dt::serde::iso_datetime_secs")]
+        secs: UTCDatetime,
+        #[serde(with = "utc_dt::serde::iso_datetime_millis")]
+        millis: UTCDatetime,
+        #[serde(with = "utc_dt::serde::iso_datetime_micros")]
+        micros: UTCDatetime,
+        #[serde(with = "utc_dt::serde::iso_datetime_nanos")]
+        nanos: UTCDatetime,
+    }
+
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903123456Z")?;
+    let event = Event {
+        secs: datetime,
+        millis: datetime,
+        micros: datetime,
+        nanos: datetime,
+    };
+    let v = serde_json::to_value(&event).unwrap();
+    assert_eq!(v["secs"], "2023-06-15T10:18:08Z");
+    assert_eq!(v["millis"], "2023-06-15T10:18:08.903Z");
+    assert_eq!(v["micros"], "2023-06-15T10:18:08.903123Z");
+    assert_eq!(v["nanos"], "2023-06-15T10:18:08.903123456Z");
+    // deserialize any precision
+    let event_from_value: Event = serde_json::from_value(v).unwrap();
+    assert_eq!(event_from_value.nanos, datetime);
+    assert_eq!(
+        event_from_value.millis,
+        UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?
+    );
+    let json = r#"{
+        "secs": "2023-06-15T10:18:08.903123456Z",
+        "millis": "2023-06-15T10:18:08Z",
+        "micros": "2023-06-15T10:18:08.9Z",
+        "nanos": "+10000-01-01T00:00:00Z"
+    }"#;
+    let event_from_str: Event = serde_json::from_str(json).unwrap();
+    assert_eq!(event_from_str.secs, datetime);
+    assert_eq!(
+        event_from_str.nanos,
+        UTCDatetime::try_from_iso_datetime("+10000-01-01T00:00:00Z")?
+    );
+    // test maximum datetime
+    let event = Event {
+        secs: UTCDatetime::MAX,
+        millis: UTCDatetime::MAX,
+        micros: UTCDatetime::MAX,
+        nanos: UTCDatetime::MAX,
+    };
+    let v = serde_json::to_value(&event).unwrap();
+    assert_eq!(v["nanos"], "+584554051223-11-09T07:00:15.999999999Z");
+    let event_from_value: Event = serde_json::from_value(v).unwrap();
+    assert_eq!(event_from_value.nanos, UTCDatetime::MAX);
+    assert!(event_from_value.secs <= UTCDatetime::MAX);
+    // test invalid str
+    let json = r#"{"secs": "invalid", "millis": "", "micros": "", "nanos": ""}"#;
+    assert!(serde_json::from_str::<Event>(json).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_datetime_serde_rfc3339() -> Result<(), UTCError> {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "utc_dt::serde::rfc3339")]
+        created: UTCDatetime,
+    }
+
+    // strs as emitted by chrono::DateTime<Utc>
+    let test_cases = [
+        "2023-06-15T10:18:08Z",
+        "2023-06-15T10:18:08.903Z",
+        "2023-06-15T10:18:08.903123Z",
+        "2023-06-15T10:18:08.903123456Z",
+        "2023-06-15T10:18:08.000000001Z",
+        "1970-01-01T00:00:00Z",
+    ];
+    for chrono_str in test_cases {
+        let created = UTCDatetime::try_from_iso_datetime(chrono_str)?;
+        let v = serde_json::to_value(Event { created }).unwrap();
+        assert_eq!(v["created"], chrono_str);
+        let event: Event = serde_json::from_value(v).unwrap();
+        assert_eq!(event.created, created);
+    }
+    // accept numeric offsets, normalizing to UTC
+    let expected = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    for rfc3339_str in [
+        "2023-06-15T10:18:08.903+00:00",
+        "2023-06-15T10:18:08.903-00:00",
+        "2023-06-15t10:18:08.903z",
+        "2023-06-15T20:18:08.903+10:00",
+        "2023-06-15T05:48:08.903-04:30",
+    ] {
+        let json = format!(r#"{{"created": "{rfc3339_str}"}}"#);
+        let event: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(event.created, expected);
+    }
+    // test invalid strs
+    for invalid_str in [
+        "",
+        "invalid",
+        "2023-06-15T10:18:08",
+        "2023-06-15T10:18:08+0000",
+    ] {
+        let json = format!(r#"{{"created": "{invalid_str}"}}"#);
+        assert!(serde_json::from_str::<Event>(&json).is_err());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn test_datetime_rkyv() -> Result<(), UTCError> {
+    use rkyv::rancor::Error;
+    use utc_dt::ArchivedUTCDatetime;
+
+    let datetime = UTCDatetime::try_from_iso_datetime("2023-06-15T10:18:08.903Z")?;
+    let bytes = rkyv::to_bytes::<Error>(&datetime).unwrap();
+    // access without deserializing, then compare the deserialized value
+    let archived = rkyv::access::<ArchivedUTCDatetime, Error>(&bytes).unwrap();
+    let deserialized = rkyv::deserialize::<UTCDatetime, Error>(archived).unwrap();
+    assert_eq!(deserialized, datetime);
+    // components archive independently
+    let (date, tod) = datetime.as_components();
+    let timestamp = datetime.as_timestamp();
+    let day = date.as_day();
+    let bytes = rkyv::to_bytes::<Error>(&date).unwrap();
+    assert_eq!(rkyv::from_bytes::<UTCDate, Error>(&bytes).unwrap(), date);
+    let bytes = rkyv::to_bytes::<Error>(&tod).unwrap();
+    assert_eq!(
+        rkyv::from_bytes::<UTCTimeOfDay, Error>(&bytes).unwrap(),
+        tod
+    );
+    let bytes = rkyv::to_bytes::<Error>(&timestamp).unwrap();
+    assert_eq!(
+        rkyv::from_bytes::<UTCTimestamp, Error>(&bytes).unwrap(),
+        timestamp
+    );
+    let bytes = rkyv::to_bytes::<Error>(&day).unwrap();
+    assert_eq!(rkyv::from_bytes::<UTCDay, Error>(&bytes).unwrap(), day);
+    Ok(())
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_datetime_arbitrary() -> Result<(), UTCError> {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    // deterministic pseudo-random input (xorshift)
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let bytes: Vec<u8> = (0..16_384)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect();
+    // include saturated inputs, generating the upper bounds
+    for input in [bytes.as_slice(), &[u8::MAX; 64], &[0; 64]] {
+        let mut u = Unstructured::new(input);
+        while u.len() > 32 {
+            let timestamp = UTCTimestamp::arbitrary(&mut u).unwrap();
+            assert!(UTCTimestamp::try_from_nanos_u128(timestamp.as_nanos()).is_ok());
+            let day = UTCDay::arbitrary(&mut u).unwrap();
+            assert_eq!(UTCDay::try_from_u64(day.as_u64())?, day);
+            let tod = UTCTimeOfDay::arbitrary(&mut u).unwrap();
+            assert_eq!(UTCTimeOfDay::try_from_nanos(tod.as_nanos())?, tod);
+            let date = UTCDate::arbitrary(&mut u).unwrap();
+            let (year, month, day) = date.as_components();
+            assert_eq!(UTCDate::try_from_components(year, month, day)?, date);
+            assert!(date <= UTCDate::MAX);
+            let datetime = UTCDatetime::arbitrary(&mut u).unwrap();
+            assert!(datetime <= UTCDatetime::MAX);
+            assert_eq!(
+                UTCDatetime::try_from_nanos_u128(datetime.as_nanos_u128()).unwrap(),
+                datetime
+            );
+        }
+    }
+    Ok(())
 }
@@ -1,24 +1,20 @@
-use core::fmt::Display;
-use utc_dt::date::{UTCDate, UTCDateError};
-use utc_dt::time::{UTCDay, UTCTimeOfDayError};
-use utc_dt::{UTCDatetimeError, UTCError};
+use core::error::Error;
+use utc_dt::date::{DateComponent, UTCDate, UTCDateError};
+use utc_dt::interval::UTCIntervalError;
+use utc_dt::time::{TimeComponent, UTCDay, UTCTimeOfDay, UTCTimeOfDayError, UTCTimestamp};
+use utc_dt::{UTCDatetime, UTCDatetimeError, UTCDatetimeFormat, UTCError};
 
-#[cfg(feature = "std")]
-fn check_errors<T: std::error::Error + Display>(errors: &[T]) {
+/// Error types implement `core::error::Error` with or without `std`, and `Clone`
+fn check_errors<T: Error + Clone>(errors: &[T]) {
     for error in errors {
+        let error_clone = error.clone();
+        assert_eq!(format!("{error}"), format!("{error_clone}"));
+        assert_eq!(format!("{error:?}"), format!("{error_clone:?}"));
         print!("Error Display test: {error}");
         if let Some(source) = error.source() {
             print!(", caused by {source}");
         }
-        print!("\n");
-    }
-}
-
-#[cfg(not(feature = "std"))]
-fn check_errors<T: Display>(errors: &[T]) {
-    for error in errors {
-        print!("Error Display test: {error}");
-        print!("\n");
+        println!();
     }
 }
 
@@ -31,6 +27,12 @@ fn test_errors() {
         UTCDateError::InvalidStrLen(30),
         UTCDateError::MonthOutOfRange(13),
         UTCDateError::YearOutOfRange(1969),
+        UTCDateError::OrdinalOutOfRange {
+            year: 2024,
+            ordinal: 367,
+            max: 366,
+        },
+        UTCDateError::InvalidFormat,
     ];
     check_errors(&utc_date_errors);
     let utc_tod_errors = [
@@ -39,8 +41,14 @@ fn test_errors() {
         UTCTimeOfDayError::ExcessMillis(0),
         UTCTimeOfDayError::ExcessNanos(0),
         UTCTimeOfDayError::ExcessSeconds(0),
+        UTCTimeOfDayError::ExcessDuration(core::time::Duration::from_secs(86_400)),
         UTCTimeOfDayError::ExcessPrecision(0),
         UTCTimeOfDayError::InsufficientStrLen(10, 20),
+        UTCTimeOfDayError::ExcessFraction(1.0),
+        UTCTimeOfDayError::ExcessSecondsF64(86_400.0),
+        UTCTimeOfDay::try_from_iso_tod("Taa:00:00Z").unwrap_err(),
+        UTCTimeOfDayError::InvalidSeparator(9),
+        UTCTimeOfDayError::ComponentOutOfRange(TimeComponent::Hour, 13),
     ];
     check_errors(&utc_tod_errors);
     let utc_day_error = [UTCDay::try_from_u64(213_503_982_334_602).unwrap_err()];
@@ -49,13 +57,34 @@ fn test_errors() {
         utc_date_errors[0].clone().into(),
         utc_tod_errors[0].clone().into(),
         UTCDatetimeError::InsufficientStrLen(10, 20),
+        UTCDatetimeError::InvalidFormat(UTCDatetimeFormat::Rfc2822),
+        UTCDatetimeError::OffsetOutOfRange(3600),
+        UTCDatetimeError::InvalidDosDatetime(0x56CF, 0xC000),
+        UTCDatetimeError::MissingComponent(DateComponent::Year),
+        UTCDatetime::try_from_nanos_u128(u128::MAX).unwrap_err(),
     ];
     check_errors(&utc_datetime_errors);
-    let utc_errors: [UTCError; 4] = [
+    let parse_any_error = [UTCDatetime::parse_any("invalid").unwrap_err()];
+    check_errors(&parse_any_error);
+    let utc_interval_errors = [UTCIntervalError::StartAfterEnd(
+        UTCDatetime::MAX,
+        UTCDatetime::MIN,
+    )];
+    check_errors(&utc_interval_errors);
+    let utc_timestamp_errors = [
+        UTCTimestamp::try_from_filetime(0).unwrap_err(),
+        UTCTimestamp::try_from_nanos_u128(u128::MAX).unwrap_err(),
+        UTCTimestamp::try_from_le_bytes([0xFF; 12]).unwrap_err(),
+    ];
+    check_errors(&utc_timestamp_errors);
+    let utc_errors: [UTCError; 7] = [
         utc_date_errors[1].clone().into(),
         utc_tod_errors[1].clone().into(),
         utc_day_error[0].clone().into(),
         utc_datetime_errors[0].clone().into(),
+        parse_any_error[0].clone().into(),
+        utc_interval_errors[0].clone().into(),
+        utc_timestamp_errors[0].clone().into(),
     ];
-    check_errors(&utc_errors.clone());
+    check_errors(&utc_errors);
 }
@@ -0,0 +1,37 @@
+use utc_dt::{
+    date::UTCDate,
+    holidays::{christmas, new_years_day, us_independence_day},
+    time::Weekday,
+    UTCError,
+};
+
+#[test]
+fn test_fixed_date_holidays() -> Result<(), UTCError> {
+    let test_cases = [
+        (1970, Weekday::Thursday, Weekday::Saturday, Weekday::Friday),
+        (2023, Weekday::Sunday, Weekday::Tuesday, Weekday::Monday),
+        (2024, Weekday::Monday, Weekday::Thursday, Weekday::Wednesday),
+    ];
+    for (year, new_years_weekday, independence_weekday, christmas_weekday) in test_cases {
+        let date = new_years_day(year)?;
+        assert_eq!(date, UTCDate::try_from_components(year, 1, 1)?);
+        assert_eq!(date.weekday(), new_years_weekday);
+        let date = us_independence_day(year)?;
+        assert_eq!(date, UTCDate::try_from_components(year, 7, 4)?);
+        assert_eq!(date.weekday(), independence_weekday);
+        let date = christmas(year)?;
+        assert_eq!(date, UTCDate::try_from_components(year, 12, 25)?);
+        assert_eq!(date.weekday(), christmas_weekday);
+        assert!(new_years_day(year)? < us_independence_day(year)?);
+        assert!(us_independence_day(year)? < christmas(year)?);
+    }
+    // out of range years
+    assert!(new_years_day(1969).is_err());
+    assert!(christmas(UTCDate::MAX_YEAR).is_err());
+    assert!(us_independence_day(UTCDate::MAX_YEAR + 1).is_err());
+    assert_eq!(
+        new_years_day(UTCDate::MAX_YEAR)?,
+        UTCDate::MAX.start_of_year()
+    );
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use core::time::Duration;
+
+use utc_dt::{interval::UTCInterval, time::UTCTransformations, UTCDatetime, UTCError};
+
+fn interval(start: &str, end: &str) -> Result<UTCInterval, UTCError> {
+    let start = UTCDatetime::try_from_iso_datetime(start)?;
+    let end = UTCDatetime::try_from_iso_datetime(end)?;
+    Ok(UTCInterval::try_from_bounds(start, end)?)
+}
+
+#[test]
+fn test_interval() -> Result<(), UTCError> {
+    let start = UTCDatetime::try_from_iso_datetime("2023-06-15T09:00:00Z")?;
+    let end = UTCDatetime::try_from_iso_datetime("2023-06-15T17:00:00Z")?;
+    let workday = UTCInterval::try_from_bounds(start, end)?;
+    assert_eq!(workday.start(), start);
+    assert_eq!(workday.end(), end);
+    assert_eq!(workday.duration(), Duration::from_secs(8 * 3600));
+    assert!(!workday.is_empty());
+    // test contains, start inclusive and end exclusive
+    assert!(workday.contains(start));
+    assert!(workday.contains(UTCDatetime::try_from_iso_datetime("2023-06-15T12:00:00Z")?));
+    assert!(!workday.contains(end));
+    assert!(!workday.contains(UTCDatetime::MIN));
+    // test empty intervals
+    let empty = UTCInterval::try_from_bounds(start, start)?;
+    assert!(empty.is_empty());
+    assert_eq!(empty.duration(), Duration::ZERO);
+    assert!(!empty.contains(start));
+    assert!(!empty.overlaps(&workday));
+    // test invalid bounds
+    assert!(UTCInterval::try_from_bounds(end, start).is_err());
+    // test limits
+    let all = UTCInterval::try_from_bounds(UTCDatetime::MIN, UTCDatetime::MAX)?;
+    assert_eq!(all.duration(), UTCDatetime::MAX.as_duration());
+    assert!(all.contains(start));
+    // test display, debug, clone & copy
+    println!("{:?}:{workday}", workday);
+    let workday_copy = workday;
+    assert_eq!(workday_copy, workday.clone());
+    Ok(())
+}
+
+#[test]
+fn test_interval_set_operations() -> Result<(), UTCError> {
+    let workday = interval("2023-06-15T09:00:00Z", "2023-06-15T17:00:00Z")?;
+    // overlapping
+    let evening = interval("2023-06-15T16:00:00Z", "2023-06-15T22:00:00Z")?;
+    assert!(workday.overlaps(&evening));
+    assert!(evening.overlaps(&workday));
+    assert_eq!(
+        workday.intersection(&evening),
+        Some(interval("2023-06-15T16:00:00Z", "2023-06-15T17:00:00Z")?)
+    );
+    assert_eq!(
+        workday.union(&evening),
+        Some(interval("2023-06-15T09:00:00Z", "2023-06-15T22:00:00Z")?)
+    );
+    // contained
+    let lunch = interval("2023-06-15T12:00:00Z", "2023-06-15T13:00:00Z")?;
+    assert!(workday.overlaps(&lunch));
+    assert_eq!(workday.intersection(&lunch), Some(lunch));
+    assert_eq!(lunch.union(&workday), Some(workday));
+    // adjacent
+    let night = interval("2023-06-15T17:00:00Z", "2023-06-16T09:00:00Z")?;
+    assert!(!workday.overlaps(&night));
+    assert!(!night.overlaps(&workday));
+    assert_eq!(workday.intersection(&night), None);
+    assert_eq!(
+        workday.union(&night),
+        Some(interval("2023-06-15T09:00:00Z", "2023-06-16T09:00:00Z")?)
+    );
+    // disjoint
+    let tomorrow = interval("2023-06-16T09:00:00Z", "2023-06-16T17:00:00Z")?;
+    assert!(!workday.overlaps(&tomorrow));
+    assert_eq!(workday.intersection(&tomorrow), None);
+    assert_eq!(workday.union(&tomorrow), None);
+    assert_eq!(tomorrow.union(&workday), None);
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_interval_serde() -> Result<(), UTCError> {
+    let workday = interval("2023-06-15T09:00:00Z", "2023-06-15T17:00:00Z")?;
+    let v = serde_json::to_value(workday).unwrap();
+    assert_eq!(workday, serde_json::from_value(v.clone()).unwrap());
+    // inverted bounds are rejected
+    let mut inverted = v;
+    let start = inverted["start"].take();
+    inverted["start"] = inverted["end"].take();
+    inverted["end"] = start;
+    let err = serde_json::from_value::<UTCInterval>(inverted).unwrap_err();
+    assert!(err.to_string().contains("exceeds interval end"));
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use utc_dt::{date::UTCDate, recurrence::MonthlyRecurrence, UTCError};
+
+#[test]
+fn test_monthly_recurrence() -> Result<(), UTCError> {
+    // day restores on long months following a clamp
+    let start = UTCDate::try_from_components(2024, 1, 31)?;
+    let expected = [
+        (2024, 1, 31),
+        (2024, 2, 29),
+        (2024, 3, 31),
+        (2024, 4, 30),
+        (2024, 5, 31),
+        (2024, 6, 30),
+        (2024, 7, 31),
+        (2024, 8, 31),
+        (2024, 9, 30),
+        (2024, 10, 31),
+        (2024, 11, 30),
+        (2024, 12, 31),
+        (2025, 1, 31),
+        (2025, 2, 28),
+        (2025, 3, 31),
+    ];
+    let recurrence = MonthlyRecurrence::new(start);
+    assert_eq!(recurrence.start(), start);
+    for (date, (year, month, day)) in recurrence.zip(expected) {
+        assert_eq!(date, UTCDate::try_from_components(year, month, day)?);
+    }
+    // days present in every month are unchanged
+    let start = UTCDate::try_from_components(2023, 6, 15)?;
+    assert!(MonthlyRecurrence::new(start)
+        .take(24)
+        .all(|date| date.as_components().2 == 15));
+    // bounded by the maximum date
+    let start = UTCDate::try_from_components(UTCDate::MAX_YEAR, 8, 31)?;
+    let mut recurrence = MonthlyRecurrence::new(start);
+    assert_eq!(recurrence.next(), Some(start));
+    assert_eq!(
+        recurrence.next(),
+        Some(UTCDate::try_from_components(UTCDate::MAX_YEAR, 9, 30)?)
+    );
+    assert_eq!(
+        recurrence.next(),
+        Some(UTCDate::try_from_components(UTCDate::MAX_YEAR, 10, 31)?)
+    );
+    assert_eq!(recurrence.next(), None);
+    assert_eq!(recurrence.next(), None);
+    assert_eq!(MonthlyRecurrence::new(UTCDate::MAX).count(), 1);
+    Ok(())
+}
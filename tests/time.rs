@@ -3,10 +3,18 @@ use std::collections::HashSet;
 
 use utc_dt::{
     constants::{MICROS_PER_DAY, MILLIS_PER_DAY, NANOS_PER_DAY, NANOS_PER_SECOND, SECONDS_PER_DAY},
-    time::{UTCDay, UTCTimeOfDay, UTCTimestamp, UTCTransformations},
-    UTCError,
+    date::UTCDate,
+    time::{
+        weekday_of_day, Saturation, TimeComponent, TimeComponents, UTCDay, UTCTimeOfDay,
+        UTCTimeOfDayError, UTCTimestamp, UTCTimestampError, UTCTransformations, Weekday,
+    },
+    UTCDatetime, UTCError,
 };
 
+fn as_secs_generic(duration: impl AsRef<Duration>) -> u64 {
+    duration.as_ref().as_secs()
+}
+
 #[test]
 fn test_utc_timestamp() -> Result<(), UTCError> {
     let test_cases = [
@@ -57,11 +65,27 @@ fn test_utc_timestamp() -> Result<(), UTCError> {
         assert_eq!(UTCDay::from_timestamp(timestamp), utc_days);
         assert_eq!(timestamp.as_tod(), tod);
         assert_eq!(utc_days.as_weekday(), weekday);
+        assert_eq!(utc_days.weekday().as_u8(), weekday);
         // test timestamp to/from days
         let timestamp_from_day = UTCTimestamp::from_day(utc_days);
         assert_eq!(timestamp_from_day.as_day(), utc_days);
         assert_eq!(timestamp_from_day.as_tod(), UTCTimeOfDay::ZERO);
         assert_eq!(timestamp_from_day, UTCTimestamp::from(utc_days));
+        // test split into day and intraday duration
+        let (split_day, intraday) = timestamp.split_day();
+        assert_eq!(split_day, utc_days);
+        assert_eq!(intraday.as_nanos() as u64, tod.as_nanos());
+        assert_eq!(UTCTimestamp::from_day(split_day) + intraday, timestamp);
+        // test timestamp to/from seconds and subsecond parts
+        let (secs, subsec_nanos) = timestamp.as_parts();
+        assert_eq!(secs, timestamp.as_secs());
+        assert_eq!(subsec_nanos, tod.as_subsec_ns());
+        assert_eq!(
+            (secs as u128 * NANOS_PER_SECOND as u128) + subsec_nanos as u128,
+            timestamp.as_nanos()
+        );
+        assert_eq!(UTCTimestamp::try_from_parts(secs, subsec_nanos)?, timestamp);
+        assert!(UTCTimestamp::try_from_parts(secs, 1_000_000_000).is_err());
         // test timestamp to/from durations
         let duration_from_timestamp = timestamp.to_duration();
         let timestamp_from_duration = UTCTimestamp::from_duration(duration_from_timestamp);
@@ -71,6 +95,14 @@ fn test_utc_timestamp() -> Result<(), UTCError> {
         );
         assert_eq!(timestamp_from_duration, expected_timestamp);
         assert_eq!(duration_from_timestamp, expected_timestamp.as_duration());
+        // timestamps may be passed to APIs generic over `AsRef<Duration>`
+        assert_eq!(
+            as_secs_generic(expected_timestamp),
+            expected_timestamp.as_secs()
+        );
+        // timestamps may be looked up by their internal duration
+        let set: HashSet<UTCTimestamp> = HashSet::from([expected_timestamp]);
+        assert!(set.contains(&duration_from_timestamp));
         // test unit conversions
         let secs_from_timestamp = expected_timestamp.as_secs();
         let millis_from_timestamp = expected_timestamp.as_millis() as u64;
@@ -93,6 +125,41 @@ fn test_utc_timestamp() -> Result<(), UTCError> {
         );
     }
 
+    // test subsecond nanos overflow rejection
+    assert_eq!(
+        UTCTimestamp::try_from_parts(u64::MAX, 999_999_999)?,
+        UTCTimestamp::MAX
+    );
+    assert!(matches!(
+        UTCTimestamp::try_from_parts(0, 1_000_000_000),
+        Err(UTCTimestampError::ExcessSubsecNanos(1_000_000_000))
+    ));
+    assert!(matches!(
+        UTCTimestamp::try_from_parts(0, u32::MAX),
+        Err(UTCTimestampError::ExcessSubsecNanos(u32::MAX))
+    ));
+
+    // test no multiplication overflow at the maximum day
+    assert_eq!(
+        UTCTimestamp::from_day(UTCDay::MAX).as_secs(),
+        UTCDay::MAX.as_u64() * SECONDS_PER_DAY
+    );
+    assert_eq!(UTCTimestamp::from_day(UTCDay::MAX).as_day(), UTCDay::MAX);
+    assert_eq!(
+        UTCTimestamp::from_day_and_tod(UTCDay::MAX, UTCTimeOfDay::MAX),
+        UTCTimestamp::MAX
+    );
+    // SAFETY: deliberately exceeds the maximum day, to test saturation
+    let beyond_max = unsafe { UTCDay::from_u64_unchecked(u64::MAX) };
+    assert_eq!(
+        UTCTimestamp::from_day(beyond_max),
+        UTCTimestamp::from_secs(u64::MAX)
+    );
+    assert_eq!(
+        UTCTimestamp::from_day_and_tod(beyond_max, UTCTimeOfDay::MAX),
+        UTCTimestamp::MAX
+    );
+
     // test from system time
     #[cfg(feature = "std")]
     let timestamp = UTCTimestamp::try_from_system_time().unwrap();
@@ -133,6 +200,50 @@ fn test_utc_timestamp() -> Result<(), UTCError> {
         timestamp.saturating_add_nanos(1000),
         timestamp.saturating_add_micros(1)
     );
+    // saturation outcomes are reported
+    assert_eq!(
+        timestamp.saturating_add_reporting(UTCTimestamp::ZERO),
+        (timestamp, Saturation::None)
+    );
+    assert_eq!(
+        timestamp.saturating_add_reporting(UTCTimestamp::MAX),
+        (UTCTimestamp::MAX, Saturation::Max)
+    );
+    assert_eq!(
+        UTCTimestamp::ZERO.saturating_add_reporting(UTCTimestamp::MAX),
+        (UTCTimestamp::MAX, Saturation::None)
+    );
+    assert_eq!(
+        timestamp.saturating_sub_reporting(timestamp),
+        (UTCTimestamp::ZERO, Saturation::None)
+    );
+    assert_eq!(
+        timestamp.saturating_sub_reporting(UTCTimestamp::MAX),
+        (UTCTimestamp::ZERO, Saturation::Min)
+    );
+    // test interpolation
+    let (a, b) = (timestamp, timestamp + Duration::from_secs(1_000));
+    assert_eq!(UTCTimestamp::lerp(a, b, 0.0), a);
+    assert_eq!(UTCTimestamp::lerp(a, b, 1.0), b);
+    assert_eq!(UTCTimestamp::lerp(a, b, 0.5), a + Duration::from_secs(500));
+    assert_eq!(UTCTimestamp::lerp(b, a, 0.5), a + Duration::from_secs(500));
+    assert_eq!(UTCTimestamp::lerp(b, a, 0.1), b - Duration::from_secs(100));
+    assert_eq!(UTCTimestamp::lerp(a, b, -1.0), a);
+    assert_eq!(UTCTimestamp::lerp(a, b, 2.0), b);
+    assert_eq!(UTCTimestamp::lerp(a, b, f64::NAN), a);
+    assert_eq!(UTCTimestamp::lerp(a, a, 0.5), a);
+    // precise over the full range
+    let (min, max) = (UTCTimestamp::ZERO, UTCTimestamp::MAX);
+    assert_eq!(UTCTimestamp::lerp(min, max, 1.0), max);
+    assert_eq!(UTCTimestamp::lerp(max, min, 1.0), min);
+    assert_eq!(
+        UTCTimestamp::lerp(min, max, 0.5).as_nanos(),
+        max.as_nanos() / 2
+    );
+    assert_eq!(
+        UTCTimestamp::lerp(max, min, 0.5).as_nanos(),
+        max.as_nanos() - (max.as_nanos() / 2)
+    );
     assert_eq!(timestamp.saturating_sub(UTCTimestamp::ZERO), timestamp);
     assert_eq!(
         timestamp.saturating_sub(UTCTimestamp::MAX),
@@ -170,6 +281,187 @@ fn test_utc_timestamp() -> Result<(), UTCError> {
         Some(UTCTimestamp::ZERO)
     );
     assert_eq!(timestamp.checked_div(u32::MIN), None);
+    // test u64 multiplication and division
+    let factor = u32::MAX as u64 + 1;
+    let second = UTCTimestamp::from_secs(1);
+    assert_eq!(
+        second.checked_mul_u64(factor),
+        Some(UTCTimestamp::from_secs(factor))
+    );
+    assert_eq!(
+        second.saturating_mul_u64(factor),
+        UTCTimestamp::from_secs(factor)
+    );
+    assert_eq!(
+        second.checked_mul_u64(u64::MAX),
+        Some(UTCTimestamp::from_secs(u64::MAX))
+    );
+    assert_eq!(
+        UTCTimestamp::from_nanos(1500).checked_mul_u64(factor),
+        Some(UTCTimestamp::try_from_nanos_u128(1500 * factor as u128)?)
+    );
+    assert_eq!(UTCTimestamp::MAX.checked_mul_u64(factor), None);
+    assert_eq!(
+        UTCTimestamp::MAX.saturating_mul_u64(factor),
+        UTCTimestamp::MAX
+    );
+    assert_eq!(
+        UTCTimestamp::MAX.checked_mul_u64(1),
+        Some(UTCTimestamp::MAX)
+    );
+    assert_eq!(timestamp.checked_mul_u64(0), Some(UTCTimestamp::ZERO));
+    assert_eq!(
+        UTCTimestamp::from_secs(factor).checked_div_u64(factor),
+        Some(second)
+    );
+    assert_eq!(
+        timestamp.checked_div_u64(u64::MAX),
+        Some(UTCTimestamp::ZERO)
+    );
+    assert_eq!(timestamp.checked_div_u64(0), None);
+    // test approximate equality
+    let millisecond = Duration::from_millis(1);
+    let timestamp = UTCTimestamp::from_millis(1_686_824_288_903);
+    assert!(timestamp.approx_eq(timestamp, Duration::ZERO));
+    for (offset, within) in [
+        (0, true),
+        (999_999, true),
+        (1_000_000, true),
+        (1_000_001, false),
+    ] {
+        let other = timestamp + Duration::from_nanos(offset);
+        assert_eq!(timestamp.approx_eq(other, millisecond), within);
+        assert_eq!(other.approx_eq(timestamp, millisecond), within);
+    }
+    assert!(UTCTimestamp::ZERO.approx_eq(UTCTimestamp::MAX, Duration::MAX));
+    assert!(!UTCTimestamp::ZERO.approx_eq(UTCTimestamp::MAX, millisecond));
+    // test overflowing subtraction
+    let (earlier, later) = (
+        UTCTimestamp::from_secs(10),
+        UTCTimestamp::from_millis(12_500),
+    );
+    assert_eq!(
+        later.overflowing_sub(earlier),
+        (UTCTimestamp::from_millis(2_500), Duration::ZERO)
+    );
+    assert_eq!(
+        earlier.overflowing_sub(later),
+        (UTCTimestamp::ZERO, Duration::from_millis(2_500))
+    );
+    assert_eq!(
+        earlier.overflowing_sub(earlier),
+        (UTCTimestamp::ZERO, Duration::ZERO)
+    );
+    assert_eq!(
+        UTCTimestamp::ZERO.overflowing_sub(UTCTimestamp::MAX),
+        (UTCTimestamp::ZERO, Duration::MAX)
+    );
+    assert_eq!(
+        earlier.overflowing_sub(later).0,
+        earlier.saturating_sub(later)
+    );
+    // test signed nanosecond addition
+    let base = UTCTimestamp::from_nanos(1_500);
+    assert_eq!(
+        base.checked_add_signed_nanos(500),
+        Some(UTCTimestamp::from_nanos(2_000))
+    );
+    assert_eq!(
+        base.checked_add_signed_nanos(-500),
+        Some(UTCTimestamp::from_nanos(1_000))
+    );
+    assert_eq!(
+        base.checked_add_signed_nanos(-1_500),
+        Some(UTCTimestamp::ZERO)
+    );
+    assert_eq!(base.checked_add_signed_nanos(-1_501), None);
+    assert_eq!(UTCTimestamp::ZERO.checked_add_signed_nanos(i128::MIN), None);
+    assert_eq!(
+        UTCTimestamp::MAX.checked_add_signed_nanos(0),
+        Some(UTCTimestamp::MAX)
+    );
+    assert_eq!(UTCTimestamp::MAX.checked_add_signed_nanos(1), None);
+    assert_eq!(UTCTimestamp::MAX.checked_add_signed_nanos(i128::MAX), None);
+    // test addition of typed day and time of day offsets
+    let base = UTCTimestamp::from_millis(1686824288903);
+    let day = UTCDay::try_from_u64(1)?;
+    let tod = UTCTimeOfDay::try_from_hhmmss(1, 2, 3, 4)?;
+    let expected =
+        UTCTimestamp::from_nanos(1686824288903000000 + NANOS_PER_DAY + 3_723_000_000_004);
+    assert_eq!(
+        base.checked_add_days(day),
+        Some(base + Duration::from_secs(SECONDS_PER_DAY))
+    );
+    assert_eq!(
+        base.checked_add_tod(tod),
+        Some(base + Duration::from_nanos(tod.as_nanos()))
+    );
+    assert_eq!(base + day + tod, expected);
+    let mut timestamp = base;
+    timestamp += day;
+    timestamp += tod;
+    assert_eq!(timestamp, expected);
+    assert_eq!(
+        UTCTimestamp::ZERO + UTCDay::MAX,
+        UTCTimestamp::from_day(UTCDay::MAX)
+    );
+    assert_eq!(UTCTimestamp::MAX.checked_add_days(day), None);
+    assert_eq!(UTCTimestamp::MAX.checked_add_tod(tod), None);
+    assert_eq!(
+        UTCTimestamp::MAX.checked_add_days(UTCDay::ZERO),
+        Some(UTCTimestamp::MAX)
+    );
+    assert_eq!(
+        UTCTimestamp::MAX.checked_add_tod(UTCTimeOfDay::ZERO),
+        Some(UTCTimestamp::MAX)
+    );
+    // test u128 nanos conversions
+    assert_eq!(
+        UTCTimestamp::try_from_nanos_u128(UTCTimestamp::MAX.as_nanos())?,
+        UTCTimestamp::MAX
+    );
+    assert_eq!(
+        UTCTimestamp::try_from_nanos_u128(timestamp.as_nanos())?,
+        timestamp
+    );
+    assert!(UTCTimestamp::try_from_nanos_u128(UTCTimestamp::MAX.as_nanos() + 1).is_err());
+    assert!(UTCTimestamp::try_from_nanos_u128(u128::MAX).is_err());
+    // test differences
+    let earlier = timestamp.saturating_sub_secs(90);
+    let diff = Duration::from_secs(90);
+    assert_eq!(timestamp.checked_duration_since(earlier), Some(diff));
+    assert_eq!(earlier.checked_duration_since(timestamp), None);
+    assert_eq!(timestamp.saturating_duration_since(earlier), diff);
+    assert_eq!(earlier.saturating_duration_since(timestamp), Duration::ZERO);
+    assert_eq!(
+        timestamp.saturating_duration_since(timestamp),
+        Duration::ZERO
+    );
+    // test seconds relative to a custom epoch (2000-01-01T00:00:00Z)
+    let custom_epoch = UTCTimestamp::from_secs(946_684_800);
+    assert_eq!(custom_epoch.seconds_since(custom_epoch), Some(0));
+    assert_eq!(
+        custom_epoch
+            .saturating_add(UTCTimestamp::from_millis(90_999))
+            .seconds_since(custom_epoch),
+        Some(90)
+    );
+    assert_eq!(
+        UTCTimestamp::MAX.seconds_since(custom_epoch),
+        Some(UTCTimestamp::MAX.as_secs() - 946_684_800)
+    );
+    assert_eq!(UTCTimestamp::ZERO.seconds_since(custom_epoch), None);
+    assert_eq!(
+        timestamp.seconds_since(UTCTimestamp::ZERO),
+        Some(timestamp.as_secs())
+    );
+    assert_eq!(timestamp.abs_diff(earlier), diff);
+    assert_eq!(earlier.abs_diff(timestamp), diff);
+    assert_eq!(timestamp.abs_diff(timestamp), Duration::ZERO);
+    assert_eq!(
+        UTCTimestamp::ZERO.abs_diff(UTCTimestamp::MAX),
+        UTCTimestamp::MAX.as_duration()
+    );
     // test operation implementations
     let one = UTCTimestamp::from_nanos(1);
     let two = UTCTimestamp::from_nanos(2);
@@ -193,6 +485,164 @@ fn test_utc_timestamp() -> Result<(), UTCError> {
     assert_eq!(assign, two);
     assign /= 2;
     assert_eq!(assign, one);
+    // test ntp conversions
+    let ntp_timestamp = UTCTimestamp::from_millis(1686824288500);
+    assert_eq!(ntp_timestamp.to_ntp(), 0xE835_63E0_8000_0000);
+    assert_eq!(UTCTimestamp::from_ntp(0xE835_63E0_8000_0000), ntp_timestamp);
+    assert_eq!(
+        UTCTimestamp::ZERO.to_ntp(),
+        UTCTimestamp::NTP_EPOCH_OFFSET_SECS << 32
+    );
+    assert_eq!(
+        UTCTimestamp::from_ntp(UTCTimestamp::NTP_EPOCH_OFFSET_SECS << 32),
+        UTCTimestamp::ZERO
+    );
+    // ntp era 1
+    assert_eq!(
+        UTCTimestamp::from_ntp(0),
+        UTCTimestamp::from_secs(2085978496)
+    );
+    assert_eq!(UTCTimestamp::from_secs(2085978496).to_ntp(), 0);
+    assert_eq!(
+        UTCTimestamp::from_ntp((UTCTimestamp::NTP_EPOCH_OFFSET_SECS - 1) << 32),
+        UTCTimestamp::from_secs(u32::MAX as u64)
+    );
+    for nanos in [0, 1, 999_999_999, 1_686_824_288_903_123_456] {
+        let timestamp = UTCTimestamp::from_nanos(nanos);
+        assert_eq!(UTCTimestamp::from_ntp(timestamp.to_ntp()), timestamp);
+    }
+    // test filetime conversions
+    assert_eq!(
+        UTCTimestamp::try_from_filetime(116444736000000000)?,
+        UTCTimestamp::ZERO
+    );
+    assert_eq!(UTCTimestamp::ZERO.to_filetime(), 116444736000000000);
+    let filetime_timestamp = UTCTimestamp::from_nanos(1686824288903123400);
+    assert_eq!(filetime_timestamp.to_filetime(), 133312978889031234);
+    assert_eq!(
+        UTCTimestamp::try_from_filetime(133312978889031234)?,
+        filetime_timestamp
+    );
+    assert_eq!(
+        UTCTimestamp::from_nanos(1686824288903123456).to_filetime(),
+        133312978889031234
+    );
+    assert!(UTCTimestamp::try_from_filetime(116444735999999999).is_err());
+    assert!(UTCTimestamp::try_from_filetime(u64::MAX).is_ok());
+    assert_eq!(UTCTimestamp::MAX.to_filetime(), u64::MAX);
+    // test hex formatting
+    assert_eq!(format!("{:x}", UTCTimestamp::from_nanos(255)), "ff");
+    assert_eq!(format!("{:X}", UTCTimestamp::from_secs(1)), "3B9ACA00");
+    assert_eq!(
+        format!("{:#010x}", UTCTimestamp::from_nanos(255)),
+        "0x000000ff"
+    );
+    // test system time conversions
+    #[cfg(feature = "std")]
+    {
+        use std::time::SystemTime;
+        let system_time = SystemTime::now();
+        let timestamp = UTCTimestamp::try_from(system_time).unwrap();
+        assert_eq!(SystemTime::from(timestamp), system_time);
+        let timestamp = UTCTimestamp::from_nanos(1_686_824_288_903_123_456);
+        let system_time = SystemTime::from(timestamp);
+        assert_eq!(
+            system_time.duration_since(SystemTime::UNIX_EPOCH).unwrap(),
+            timestamp.as_duration()
+        );
+        assert_eq!(UTCTimestamp::try_from(system_time).unwrap(), timestamp);
+        assert_eq!(SystemTime::from(UTCTimestamp::ZERO), SystemTime::UNIX_EPOCH);
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+        assert!(UTCTimestamp::try_from(before_epoch).is_err());
+    }
+    // test projection of monotonic instants
+    #[cfg(feature = "std")]
+    {
+        use std::time::Instant;
+        let anchor_instant = Instant::now();
+        let anchor_ts = UTCTimestamp::from_nanos(1_686_824_288_903_123_456);
+        let later = anchor_instant + Duration::from_millis(1500);
+        assert_eq!(
+            UTCTimestamp::since_instant_anchor(later, anchor_instant, anchor_ts),
+            anchor_ts + Duration::from_millis(1500)
+        );
+        assert_eq!(
+            UTCTimestamp::since_instant_anchor(anchor_instant, anchor_instant, anchor_ts),
+            anchor_ts
+        );
+        let earlier = later - Duration::from_secs(3);
+        assert_eq!(
+            UTCTimestamp::since_instant_anchor(earlier, later, anchor_ts),
+            anchor_ts - Duration::from_secs(3)
+        );
+        // saturating
+        assert_eq!(
+            UTCTimestamp::since_instant_anchor(anchor_instant, later, UTCTimestamp::ZERO),
+            UTCTimestamp::ZERO
+        );
+        assert_eq!(
+            UTCTimestamp::since_instant_anchor(later, anchor_instant, UTCTimestamp::MAX),
+            UTCTimestamp::MAX
+        );
+    }
+    // test stable hashes
+    assert_eq!(UTCTimestamp::ZERO.fnv1a_hash(), 9808874869469701221);
+    assert_eq!(
+        UTCTimestamp::from_nanos(1_686_824_288_903_000_000).fnv1a_hash(),
+        7057420398610431472
+    );
+    // test byte representations
+    let timestamp = UTCTimestamp::from_nanos(0x0102_0304_0506_0708);
+    let le_bytes = timestamp.to_le_bytes();
+    let be_bytes = timestamp.to_be_bytes();
+    assert_eq!(le_bytes[..8], timestamp.as_secs().to_le_bytes());
+    assert_eq!(
+        le_bytes[8..],
+        timestamp.as_duration().subsec_nanos().to_le_bytes()
+    );
+    assert_eq!(be_bytes[..8], timestamp.as_secs().to_be_bytes());
+    assert_eq!(
+        be_bytes[8..],
+        timestamp.as_duration().subsec_nanos().to_be_bytes()
+    );
+    let timestamp = UTCTimestamp::from_duration(Duration::new(0x0102_0304_0506_0708, 0x0A0B_0C0D));
+    assert_eq!(
+        timestamp.to_le_bytes(),
+        [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x0D, 0x0C, 0x0B, 0x0A]
+    );
+    assert_eq!(
+        timestamp.to_be_bytes(),
+        [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x0A, 0x0B, 0x0C, 0x0D]
+    );
+    for timestamp in [UTCTimestamp::ZERO, timestamp, UTCTimestamp::MAX] {
+        assert_eq!(
+            UTCTimestamp::try_from_le_bytes(timestamp.to_le_bytes())?,
+            timestamp
+        );
+        assert_eq!(
+            UTCTimestamp::try_from_be_bytes(timestamp.to_be_bytes())?,
+            timestamp
+        );
+    }
+    let mut bytes = UTCTimestamp::MAX.to_le_bytes();
+    bytes[8..].copy_from_slice(&(NANOS_PER_SECOND as u32).to_le_bytes());
+    assert!(UTCTimestamp::try_from_le_bytes(bytes).is_err());
+    assert!(UTCTimestamp::try_from_be_bytes([0xFF; UTCTimestamp::BYTES_LEN]).is_err());
+    // test ISO datetime display
+    let timestamp = UTCTimestamp::from_nanos(1_686_824_288_903_123_456);
+    let datetime = UTCDatetime::from(timestamp);
+    assert_eq!(format!("{timestamp}"), "2023-06-15T10:18:08.903Z");
+    assert_eq!(format!("{timestamp}"), format!("{datetime:.3}"));
+    assert_eq!(format!("{timestamp:.9}"), format!("{datetime}"));
+    assert_eq!(format!("{timestamp:.0}"), "2023-06-15T10:18:08Z");
+    assert_eq!(
+        format!("{}", UTCTimestamp::ZERO),
+        "1970-01-01T00:00:00.000Z"
+    );
+    assert_eq!(
+        format!("{}", UTCTimestamp::MAX),
+        format!("{:.3}", UTCDatetime::MAX)
+    );
     Ok(())
 }
 
@@ -207,6 +657,79 @@ fn test_utc_day() -> Result<(), UTCError> {
     assert!(utc_day >= UTCDay::ZERO);
     // test debug
     println!("{:?} (days since epoch)", utc_day);
+    // test hex formatting
+    assert_eq!(format!("{:x}", UTCDay::try_from_u64(255)?), "ff");
+    assert_eq!(format!("{:X}", UTCDay::try_from_u64(255)?), "FF");
+    assert_eq!(
+        format!("{:x}", UTCDay::MAX),
+        format!("{:x}", UTCDay::MAX.as_u64())
+    );
+    // test stable hashes
+    assert_eq!(UTCDay::ZERO.fnv1a_hash(), 12161962213042174405);
+    assert_eq!(
+        UTCDay::try_from_u64(19523)?.fnv1a_hash(),
+        8193008030969051050
+    );
+    // test byte representations
+    let day = UTCDay::try_from_u64(0x0102_0304)?;
+    assert_eq!(day.to_le_bytes(), [0x04, 0x03, 0x02, 0x01, 0, 0, 0, 0]);
+    assert_eq!(day.to_be_bytes(), [0, 0, 0, 0, 0x01, 0x02, 0x03, 0x04]);
+    for day in [UTCDay::ZERO, day, UTCDay::MAX] {
+        assert_eq!(UTCDay::try_from_le_bytes(day.to_le_bytes())?, day);
+        assert_eq!(UTCDay::try_from_be_bytes(day.to_be_bytes())?, day);
+    }
+    assert!(UTCDay::try_from_le_bytes((UTCDay::MAX.as_u64() + 1).to_le_bytes()).is_err());
+    assert!(UTCDay::try_from_be_bytes([0xFF; 8]).is_err());
+    // test packed u32 representation
+    assert_eq!(UTCDay::from_u32(0), UTCDay::ZERO);
+    assert_eq!(UTCDay::ZERO.to_u32(), Some(0));
+    let max_u32_day = UTCDay::from_u32(u32::MAX);
+    assert_eq!(max_u32_day.as_u64(), u32::MAX as u64);
+    assert_eq!(max_u32_day.to_u32(), Some(u32::MAX));
+    assert_eq!(
+        UTCDate::from_day(max_u32_day),
+        UTCDate::try_from_components(11_761_191, 1, 20)?
+    );
+    assert_eq!(UTCDay::try_from_u64(u32::MAX as u64 + 1)?.to_u32(), None);
+    assert_eq!(UTCDay::MAX.to_u32(), None);
+    // test signed addition
+    let day = UTCDay::try_from_u64(19523)?;
+    assert_eq!(
+        day.checked_add_signed(-3),
+        Some(UTCDay::try_from_u64(19520)?)
+    );
+    assert_eq!(
+        day.checked_add_signed(3),
+        Some(UTCDay::try_from_u64(19526)?)
+    );
+    assert_eq!(day.checked_add_signed(0), Some(day));
+    assert_eq!(day.checked_add_signed(-19523), Some(UTCDay::ZERO));
+    assert_eq!(day.checked_add_signed(-19524), None);
+    assert_eq!(day.checked_add_signed(i64::MIN), None);
+    assert_eq!(UTCDay::MAX.checked_add_signed(0), Some(UTCDay::MAX));
+    assert_eq!(UTCDay::MAX.checked_add_signed(1), None);
+    assert_eq!(UTCDay::ZERO.checked_add_signed(i64::MAX), None);
+    assert_eq!(day.saturating_add_signed(-19523), UTCDay::ZERO);
+    assert_eq!(day.saturating_add_signed(-19524), UTCDay::ZERO);
+    assert_eq!(day.saturating_add_signed(i64::MIN), UTCDay::ZERO);
+    assert_eq!(day.saturating_add_signed(-3), UTCDay::try_from_u64(19520)?);
+    assert_eq!(UTCDay::MAX.saturating_add_signed(1), UTCDay::MAX);
+    assert_eq!(day.saturating_add_signed(i64::MAX), UTCDay::MAX);
+    // test ISO weekdays, from a known thursday (2023-06-15)
+    let thursday = UTCDay::try_from_u64(19523)?;
+    assert_eq!(thursday.weekday(), Weekday::Thursday);
+    assert_eq!(thursday.as_iso_weekday(), 4);
+    assert_eq!(UTCDay::ZERO.as_iso_weekday(), 4);
+    for offset in 0..7 {
+        let day = thursday + offset;
+        let iso_weekday = day.as_iso_weekday();
+        assert!((1..=7).contains(&iso_weekday));
+        // ISO weekday agrees with sunday-based weekday, with sunday as 7
+        assert_eq!(iso_weekday % 7, day.as_weekday());
+    }
+    assert_eq!((thursday + 3).as_iso_weekday(), 7);
+    assert_eq!((thursday + 4).as_iso_weekday(), 1);
+    assert!((1..=7).contains(&UTCDay::MAX.as_iso_weekday()));
     // test from u64
     let u64_from_max = UTCDay::MAX.to_u64();
     let u64_invalid = u64_from_max + 1;
@@ -255,6 +778,33 @@ fn test_utc_day() -> Result<(), UTCError> {
     );
     assert_eq!(utc_day.saturating_add_u64(0), utc_day);
     assert_eq!(utc_day.saturating_add_u64(u64::MAX), UTCDay::MAX);
+    // saturation outcomes are reported
+    assert_eq!(
+        utc_day.saturating_add_reporting(UTCDay::ZERO),
+        (utc_day, Saturation::None)
+    );
+    assert_eq!(
+        utc_day.saturating_add_reporting(UTCDay::MAX),
+        (UTCDay::MAX, Saturation::Max)
+    );
+    // SAFETY: deliberately exceeds the maximum day, to test saturation
+    assert_eq!(
+        utc_day.saturating_add_reporting(unsafe { UTCDay::from_u64_unchecked(u64::MAX) }),
+        (UTCDay::MAX, Saturation::Max)
+    );
+    assert_eq!(
+        UTCDay::ZERO.saturating_add_reporting(UTCDay::MAX),
+        (UTCDay::MAX, Saturation::None)
+    );
+    assert_eq!(
+        utc_day.saturating_sub_reporting(utc_day),
+        (UTCDay::ZERO, Saturation::None)
+    );
+    assert_eq!(
+        utc_day.saturating_sub_reporting(UTCDay::MAX),
+        (UTCDay::ZERO, Saturation::Min)
+    );
+    assert_eq!(Saturation::default(), Saturation::None);
     assert_eq!(utc_day.saturating_sub(UTCDay::ZERO), utc_day);
     assert_eq!(utc_day.saturating_sub(UTCDay::MAX), UTCDay::ZERO);
     assert_eq!(utc_day.saturating_sub_u64(0), utc_day);
@@ -263,6 +813,19 @@ fn test_utc_day() -> Result<(), UTCError> {
     assert_eq!(utc_day.saturating_mul(u64::MAX), UTCDay::MAX);
     assert_eq!(utc_day.checked_div(u64::MAX), Some(UTCDay::ZERO));
     assert_eq!(utc_day.checked_div(u64::MIN), None);
+    // test summing day counts
+    let spans = [
+        UTCDay::try_from_u64(1)?,
+        UTCDay::try_from_u64(30)?,
+        UTCDay::try_from_u64(365)?,
+    ];
+    assert_eq!(spans.iter().sum::<UTCDay>(), UTCDay::try_from_u64(396)?);
+    assert_eq!(
+        spans.into_iter().sum::<UTCDay>(),
+        UTCDay::try_from_u64(396)?
+    );
+    assert_eq!(core::iter::empty::<UTCDay>().sum::<UTCDay>(), UTCDay::ZERO);
+    assert_eq!([UTCDay::MAX, utc_day].iter().sum::<UTCDay>(), UTCDay::MAX);
     // test operation implementations
     let one = UTCDay::try_from_u64(1)?;
     let two = UTCDay::try_from_u64(2)?;
@@ -288,6 +851,44 @@ fn test_utc_day() -> Result<(), UTCError> {
     Ok(())
 }
 
+#[test]
+fn test_weekday() {
+    for (i, weekday) in Weekday::ALL.into_iter().enumerate() {
+        assert_eq!(weekday.as_u8() as usize, i);
+        assert_eq!(weekday.days_until(weekday), 0);
+        println!("{:?}:{weekday}", weekday);
+    }
+    assert_eq!(UTCDay::ZERO.weekday(), Weekday::Thursday);
+    assert_eq!(Weekday::Thursday.to_string(), "Thursday");
+    assert_eq!(Weekday::Thursday.days_until(Weekday::Monday), 4);
+    assert_eq!(Weekday::Monday.days_until(Weekday::Thursday), 3);
+    assert_eq!(Weekday::Saturday.days_until(Weekday::Sunday), 1);
+    assert_eq!(Weekday::Sunday.days_until(Weekday::Saturday), 6);
+    // test const evaluation from integer day counts
+    const WEEKDAYS: [(u8, Weekday); 3] = [
+        (weekday_of_day(0), Weekday::of_day(0)),
+        (weekday_of_day(3), Weekday::of_day(3)),
+        (weekday_of_day(u64::MAX), Weekday::of_day(u64::MAX)),
+    ];
+    assert_eq!(WEEKDAYS[0], (4, Weekday::Thursday));
+    assert_eq!(WEEKDAYS[1], (0, Weekday::Sunday));
+    assert_eq!(WEEKDAYS[2].0, WEEKDAYS[2].1.as_u8());
+    assert_eq!(WEEKDAYS[2].1, Weekday::of_day(u64::MAX % 7));
+    for day in [0, 1, 19_523, UTCDay::MAX.as_u64()] {
+        let utc_day = UTCDay::try_from_u64(day).unwrap();
+        assert_eq!(weekday_of_day(day), utc_day.as_weekday());
+        assert_eq!(Weekday::of_day(day), utc_day.weekday());
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "unchecked UTCTimeOfDay exceeds a day")]
+fn test_utc_tod_unchecked_debug_assertion() {
+    // SAFETY: deliberately invalid, to test the debug assertion
+    let _ = unsafe { UTCTimeOfDay::from_nanos_unchecked(NANOS_PER_DAY) };
+}
+
 #[test]
 fn test_utc_tod() -> Result<(), UTCError> {
     // test from system time
@@ -309,9 +910,60 @@ fn test_utc_tod() -> Result<(), UTCError> {
     assert!(UTCTimeOfDay::try_from_hhmmss(24, 0, 0, 0).is_err());
     assert!(UTCTimeOfDay::try_from_hhmmss(23, 59, 59, (NANOS_PER_SECOND - 1) as u32).is_ok());
     assert!(UTCTimeOfDay::try_from_hhmmss(u8::MAX, u8::MAX, u8::MAX, u32::MAX).is_err());
+    // test named components
+    let components = tod_from_timestamp.components();
+    assert_eq!(
+        (components.hours, components.minutes, components.seconds),
+        (hrs, mins, secs)
+    );
+    assert_eq!(components.subsec_nanos, subsec_ns);
+    assert_eq!(TimeComponents::from(tod_from_timestamp), components);
+    assert_eq!(
+        UTCTimeOfDay::try_from_time_components(components)?,
+        tod_from_timestamp
+    );
+    assert_eq!(UTCTimeOfDay::try_from(components)?, tod_from_timestamp);
+    assert_eq!(
+        UTCTimeOfDay::try_from(TimeComponents::default())?,
+        UTCTimeOfDay::ZERO
+    );
+    let components = TimeComponents {
+        hours: 23,
+        minutes: 59,
+        seconds: 59,
+        subsec_nanos: 999_999_999,
+    };
+    assert_eq!(UTCTimeOfDay::try_from(components)?, UTCTimeOfDay::MAX);
+    assert_eq!(UTCTimeOfDay::MAX.components(), components);
+    assert!(UTCTimeOfDay::try_from(TimeComponents {
+        hours: 24,
+        ..Default::default()
+    })
+    .is_err());
+    // test from duration
+    let duration = Duration::from_nanos(tod_from_timestamp.as_nanos());
+    assert_eq!(
+        UTCTimeOfDay::try_from_duration(duration)?,
+        tod_from_timestamp
+    );
+    assert_eq!(
+        UTCTimeOfDay::try_from_duration(Duration::from_nanos(NANOS_PER_DAY - 1))?,
+        UTCTimeOfDay::try_from_hhmmss(23, 59, 59, (NANOS_PER_SECOND - 1) as u32)?
+    );
+    assert_eq!(
+        UTCTimeOfDay::try_from_duration(Duration::ZERO)?,
+        UTCTimeOfDay::ZERO
+    );
+    assert!(matches!(
+        UTCTimeOfDay::try_from_duration(Duration::from_secs(SECONDS_PER_DAY)),
+        Err(UTCTimeOfDayError::ExcessDuration(_))
+    ));
+    assert!(UTCTimeOfDay::try_from_duration(Duration::MAX).is_err());
     // test iso conversions
     #[cfg(feature = "alloc")]
-    let iso_from_tod = tod_from_timestamp.as_iso_tod(9);
+    let iso_string = tod_from_timestamp.as_iso_tod(9);
+    #[cfg(feature = "alloc")]
+    let iso_from_tod = iso_string.as_str();
     #[cfg(not(feature = "alloc"))]
     let mut buf = [0; UTCTimeOfDay::iso_tod_len(9)];
     #[cfg(not(feature = "alloc"))]
@@ -319,7 +971,7 @@ fn test_utc_tod() -> Result<(), UTCError> {
         let _ = tod_from_timestamp.write_iso_tod(&mut buf, 9)?;
         core::str::from_utf8(&buf).unwrap()
     };
-    let tod_from_iso = UTCTimeOfDay::try_from_iso_tod(&iso_from_tod)?;
+    let tod_from_iso = UTCTimeOfDay::try_from_iso_tod(iso_from_tod)?;
     assert_eq!(tod_from_iso, tod_from_timestamp);
     assert_eq!(
         UTCTimeOfDay::try_from_iso_tod("T00:00:00Z")?,
@@ -341,14 +993,182 @@ fn test_utc_tod() -> Result<(), UTCError> {
     assert!(UTCTimeOfDay::try_from_iso_tod("T23::59.9999999990Z").is_err());
     assert!(UTCTimeOfDay::try_from_iso_tod("T23:59.9999999990Z").is_err());
     assert!(UTCTimeOfDay::try_from_iso_tod("T23:59:59").is_err());
+    // test fractional precision edge cases
+    assert_eq!(
+        UTCTimeOfDay::try_from_iso_tod("T10:18:08.9Z")?,
+        UTCTimeOfDay::try_from_hhmmss(10, 18, 8, 900_000_000)?
+    );
+    assert_eq!(
+        UTCTimeOfDay::try_from_iso_tod("T10:18:08.Z")?,
+        UTCTimeOfDay::try_from_hhmmss(10, 18, 8, 0)?
+    );
+    assert!(matches!(
+        UTCTimeOfDay::try_from_iso_tod("T10:18:08.0000000000Z"),
+        Err(UTCTimeOfDayError::ExcessPrecision(10))
+    ));
+    // test invalid separator positions, including a missing zone designator
+    let test_cases = [
+        ("10:18:08.903Z", 0),
+        ("X10:18:08Z", 0),
+        ("T10-18:08Z", 3),
+        ("T10:18-08Z", 6),
+        ("T10:18:08,903Z", 9),
+        ("T10:18:08.903", 12),
+        ("T10:18:08.90", 11),
+        ("T10:18:08X", 9),
+        ("T10:18:08é", 10),
+    ];
+    for (iso, expected_offset) in test_cases {
+        match UTCTimeOfDay::try_from_iso_tod(iso) {
+            Err(UTCTimeOfDayError::InvalidSeparator(offset)) => assert_eq!(offset, expected_offset),
+            result => panic!("unexpected result for {iso}: {result:?}"),
+        }
+    }
+    // test parse failure positions
+    let test_cases = [
+        ("Taa:59:59Z", TimeComponent::Hour, 1, "aa"),
+        ("T23:aa:59Z", TimeComponent::Minute, 4, "aa"),
+        ("T23:59:aaZ", TimeComponent::Second, 7, "aa"),
+        ("T23:59:59.a99Z", TimeComponent::Subsecond, 10, "a99"),
+        (
+            "T23:59:59.123456a89Z",
+            TimeComponent::Subsecond,
+            10,
+            "123456a89",
+        ),
+        ("Té:59:59Z", TimeComponent::Hour, 1, "é"),
+    ];
+    for (iso, expected_component, expected_offset, expected_substr) in test_cases {
+        match UTCTimeOfDay::try_from_iso_tod(iso) {
+            Err(UTCTimeOfDayError::InvalidComponent(component, offset, substr, _)) => {
+                assert_eq!(component, expected_component);
+                assert_eq!(offset, expected_offset);
+                assert_eq!(substr.as_str(), expected_substr);
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+    // test 12-hour clock parsing
+    let test_cases = [
+        ("12:00 AM", (0, 0, 0)),
+        ("12:00 PM", (12, 0, 0)),
+        ("12:59:59 AM", (0, 59, 59)),
+        ("12:30 PM", (12, 30, 0)),
+        ("1:00 AM", (1, 0, 0)),
+        ("01:00 PM", (13, 0, 0)),
+        ("10:18:08 AM", (10, 18, 8)),
+        ("10:18:08 PM", (22, 18, 8)),
+        ("11:59:59 PM", (23, 59, 59)),
+        ("9:05am", (9, 5, 0)),
+        ("9:05 pm", (21, 5, 0)),
+    ];
+    for (s, (hrs, mins, secs)) in test_cases {
+        assert_eq!(
+            UTCTimeOfDay::try_from_12h(s)?,
+            UTCTimeOfDay::try_from_hhmmss(hrs, mins, secs, 0)?
+        );
+    }
+    let test_cases = [
+        ("", 0),
+        ("AM", 0),
+        ("10:18", 3),
+        ("10:18 XM", 6),
+        ("10:18  AM", 6),
+        ("10-18 AM", 2),
+        ("10:18-08 AM", 5),
+        ("10:1 AM", 4),
+        ("100:18 AM", 6),
+        ("10:18:080 AM", 9),
+        ("10:18 ÀM", 6),
+    ];
+    for (s, expected_offset) in test_cases {
+        match UTCTimeOfDay::try_from_12h(s) {
+            Err(UTCTimeOfDayError::InvalidSeparator(offset)) => assert_eq!(offset, expected_offset),
+            result => panic!("unexpected result for {s}: {result:?}"),
+        }
+    }
+    let test_cases = [
+        ("0:00 AM", TimeComponent::Hour, 0),
+        ("13:00 PM", TimeComponent::Hour, 13),
+        ("10:60 AM", TimeComponent::Minute, 60),
+        ("10:18:60 PM", TimeComponent::Second, 60),
+    ];
+    for (s, expected_component, expected_value) in test_cases {
+        match UTCTimeOfDay::try_from_12h(s) {
+            Err(UTCTimeOfDayError::ComponentOutOfRange(component, value)) => {
+                assert_eq!(component, expected_component);
+                assert_eq!(value, expected_value);
+            }
+            result => panic!("unexpected result for {s}: {result:?}"),
+        }
+    }
+    let err = UTCTimeOfDay::try_from_12h("1a:00 AM").unwrap_err();
+    match &err {
+        UTCTimeOfDayError::InvalidComponent(TimeComponent::Hour, 0, substr, _) => {
+            assert_eq!(substr.as_str(), "1a");
+        }
+        e => panic!("unexpected error: {e:?}"),
+    }
+    assert!(err
+        .to_string()
+        .starts_with("invalid hour (\"1a\") at offset 0"));
+    let err = UTCTimeOfDay::try_from_12h("10:18:a8 AM").unwrap_err();
+    match &err {
+        UTCTimeOfDayError::InvalidComponent(TimeComponent::Second, 6, substr, _) => {
+            assert_eq!(substr.as_str(), "a8");
+        }
+        e => panic!("unexpected error: {e:?}"),
+    }
+    assert!(err
+        .to_string()
+        .starts_with("invalid second (\"a8\") at offset 6"));
+    assert!(UTCTimeOfDay::try_from_iso_tod("T10:18:08 AM").is_err());
+    // test stack allocated iso conversions
+    let tod = UTCTimeOfDay::try_from_iso_tod("T10:18:08.903Z")?;
+    assert_eq!(tod.to_iso_array::<0>(), "T10:18:08Z");
+    assert_eq!(tod.to_iso_array::<3>(), "T10:18:08.903Z");
+    assert_eq!(tod.to_iso_array::<9>(), "T10:18:08.903000000Z");
+    assert_eq!(tod.to_iso_array::<12>(), "T10:18:08.903000000Z");
+    assert_eq!(tod.to_iso_array::<3>().len(), UTCTimeOfDay::iso_tod_len(3));
+    assert_eq!(
+        UTCTimeOfDay::try_from_iso_tod(&tod.to_iso_array::<3>())?,
+        tod
+    );
+    println!("{:?}:{}", tod.to_iso_array::<6>(), tod.to_iso_array::<6>());
+    // test precision 0 writes no decimal separator, to an odd length buffer
+    let mut buf = [0xAA; UTCTimeOfDay::MIN_ISO_TOD_LEN + 1];
+    let written = tod.write_iso_tod(&mut buf, 0)?;
+    assert_eq!(written, UTCTimeOfDay::iso_tod_len(0));
+    assert_eq!(&buf[..written], b"T10:18:08Z");
+    assert_eq!(buf[written], 0xAA);
+    assert!(!buf.contains(&b'.'));
+    // test worst-case iso length constant, evaluated at compile time
+    const _: () = assert!(UTCTimeOfDay::MAX_ISO_TOD_LEN == UTCTimeOfDay::iso_tod_len(9));
+    assert_eq!(UTCTimeOfDay::MAX_ISO_TOD_LEN, 20);
     // test no-alloc iso conversions
-    let mut buf = [0; UTCTimeOfDay::iso_tod_len(9)];
+    let mut buf = [0; UTCTimeOfDay::MAX_ISO_TOD_LEN];
     for precision in 0..13 {
         let written = tod_from_timestamp.write_iso_tod(&mut buf, precision)?;
         let iso_raw_str = core::str::from_utf8(&buf[..written]).unwrap();
         assert_eq!(iso_raw_str.len(), UTCTimeOfDay::iso_tod_len(precision));
         #[cfg(feature = "alloc")]
-        assert_eq!(tod_from_timestamp.as_iso_tod(precision), iso_raw_str);
+        {
+            assert_eq!(tod_from_timestamp.as_iso_tod(precision), iso_raw_str);
+            // strict conversion errors rather than clamping excess precision
+            match tod_from_timestamp.try_as_iso_tod(precision) {
+                Ok(iso) => {
+                    assert!(precision <= UTCTimeOfDay::MAX_ISO_TOD_PRECISION);
+                    assert_eq!(iso, iso_raw_str);
+                }
+                Err(UTCTimeOfDayError::ExcessPrecision(p)) => {
+                    assert!(precision > UTCTimeOfDay::MAX_ISO_TOD_PRECISION);
+                    assert_eq!(p as usize, precision);
+                }
+                Err(e) => panic!("unexpected error: {e}"),
+            }
+        }
+        // display at formatter precision matches
+        assert_eq!(format!("{tod_from_timestamp:.precision$}"), iso_raw_str);
         // test maybe-invalid buf len
         let mut buf = [0; 5];
         let result = tod_from_timestamp.write_iso_tod(&mut buf, precision);
@@ -377,6 +1197,153 @@ fn test_utc_tod() -> Result<(), UTCError> {
     assert!(tod_from_micros <= tod_from_timestamp);
     assert_eq!(tod_from_nanos, tod_from_timestamp);
     assert_eq!(nanos_from_tod, tod_from_nanos.to_nanos());
+    // test fraction of day conversions
+    assert_eq!(UTCTimeOfDay::ZERO.as_fraction_of_day(), 0.0);
+    assert_eq!(
+        UTCTimeOfDay::try_from_hhmmss(12, 0, 0, 0)?.as_fraction_of_day(),
+        0.5
+    );
+    assert!(UTCTimeOfDay::MAX.as_fraction_of_day() < 1.0);
+    assert_eq!(UTCTimeOfDay::from_fraction_of_day(0.0)?, UTCTimeOfDay::ZERO);
+    assert_eq!(
+        UTCTimeOfDay::from_fraction_of_day(0.5)?,
+        UTCTimeOfDay::try_from_hhmmss(12, 0, 0, 0)?
+    );
+    let fraction = tod_from_secs.as_fraction_of_day();
+    assert!(
+        UTCTimeOfDay::from_fraction_of_day(fraction)?
+            .as_secs()
+            .abs_diff(secs_from_tod)
+            <= 1
+    );
+    assert!(UTCTimeOfDay::from_fraction_of_day(1.0).is_err());
+    assert!(UTCTimeOfDay::from_fraction_of_day(-0.1).is_err());
+    assert!(UTCTimeOfDay::from_fraction_of_day(f64::NAN).is_err());
+    // test fractional seconds
+    let tod = UTCTimeOfDay::from_secs_f64(37_088.903)?;
+    assert_eq!(tod, UTCTimeOfDay::try_from_hhmmss(10, 18, 8, 903_000_000)?);
+    assert!((tod.as_secs_f64() - 37_088.903).abs() < 1e-9);
+    assert_eq!(UTCTimeOfDay::from_secs_f64(0.0)?, UTCTimeOfDay::ZERO);
+    assert_eq!(UTCTimeOfDay::ZERO.as_secs_f64(), 0.0);
+    assert_eq!(
+        UTCTimeOfDay::from_secs_f64(UTCTimeOfDay::MAX.as_secs_f64())?,
+        UTCTimeOfDay::MAX
+    );
+    assert_eq!(
+        UTCTimeOfDay::from_secs_f64(86_399.999_999_999_99)?,
+        UTCTimeOfDay::MAX
+    );
+    assert!(UTCTimeOfDay::from_secs_f64(86_400.0).is_err());
+    assert!(UTCTimeOfDay::from_secs_f64(-0.1).is_err());
+    assert!(UTCTimeOfDay::from_secs_f64(f64::NAN).is_err());
+    assert!(UTCTimeOfDay::from_secs_f64(f64::INFINITY).is_err());
+    // test normalized construction with day carry
+    let test_cases = [
+        ((25, 0, 0, 0), 1, (1, 0, 0, 0)),
+        ((23, 59, 59, 999_999_999), 0, (23, 59, 59, 999_999_999)),
+        ((23, 60, 0, 0), 1, (0, 0, 0, 0)),
+        ((11, 90, 0, 0), 0, (12, 30, 0, 0)),
+        ((0, 0, 255, 0), 0, (0, 4, 15, 0)),
+        ((48, 0, 0, 1), 2, (0, 0, 0, 1)),
+        (
+            (u8::MAX, u8::MAX, u8::MAX, u32::MAX),
+            10,
+            (19, 19, 19, 294_967_295),
+        ),
+    ];
+    for ((hrs, mins, secs, subsec_ns), expected_carry, (h, m, s, ns)) in test_cases {
+        let (carry, tod) = UTCTimeOfDay::from_hhmmss_normalized(hrs, mins, secs, subsec_ns);
+        assert_eq!(carry, expected_carry);
+        assert_eq!(tod, UTCTimeOfDay::try_from_hhmmss(h, m, s, ns)?);
+        assert_eq!(
+            carry == 0,
+            UTCTimeOfDay::try_from_hhmmss(hrs, mins, secs, subsec_ns).is_ok()
+        );
+    }
+    // test time of day windows
+    let hhmm = |h, m| UTCTimeOfDay::try_from_hhmmss(h, m, 0, 0);
+    let (nine_am, five_pm) = (hhmm(9, 0)?, hhmm(17, 0)?);
+    assert!(nine_am.is_between(nine_am, five_pm));
+    assert!(hhmm(12, 30)?.is_between(nine_am, five_pm));
+    assert!(!five_pm.is_between(nine_am, five_pm));
+    assert!(!hhmm(8, 59)?.is_between(nine_am, five_pm));
+    assert!(!hhmm(23, 0)?.is_between(nine_am, five_pm));
+    let (ten_pm, six_am) = (hhmm(22, 0)?, hhmm(6, 0)?);
+    assert!(ten_pm.is_between(ten_pm, six_am));
+    assert!(UTCTimeOfDay::MAX.is_between(ten_pm, six_am));
+    assert!(UTCTimeOfDay::ZERO.is_between(ten_pm, six_am));
+    assert!(hhmm(5, 59)?.is_between(ten_pm, six_am));
+    assert!(!six_am.is_between(ten_pm, six_am));
+    assert!(!hhmm(12, 0)?.is_between(ten_pm, six_am));
+    assert!(!hhmm(21, 59)?.is_between(ten_pm, six_am));
+    // empty and full day windows
+    assert!(!nine_am.is_between(nine_am, nine_am));
+    assert!(!UTCTimeOfDay::MAX.is_between(UTCTimeOfDay::ZERO, UTCTimeOfDay::MAX));
+    assert!(hhmm(12, 0)?.is_between(UTCTimeOfDay::ZERO, UTCTimeOfDay::MAX));
+    // test snapping to the nearest minute and hour
+    let hhmmss = |h, m, s| UTCTimeOfDay::try_from_hhmmss(h, m, s, 0);
+    assert_eq!(hhmmss(10, 29, 30)?.snap_to_minute(), (hhmm(10, 30)?, 0));
+    assert_eq!(hhmmss(10, 29, 29)?.snap_to_minute(), (hhmm(10, 29)?, 0));
+    assert_eq!(
+        hhmmss(23, 59, 59)?.snap_to_minute(),
+        (UTCTimeOfDay::ZERO, 1)
+    );
+    assert_eq!(UTCTimeOfDay::MAX.snap_to_minute(), (UTCTimeOfDay::ZERO, 1));
+    assert_eq!(UTCTimeOfDay::ZERO.snap_to_minute(), (UTCTimeOfDay::ZERO, 0));
+    assert_eq!(hhmm(10, 30)?.snap_to_hour(), (hhmm(11, 0)?, 0));
+    assert_eq!(hhmmss(10, 29, 59)?.snap_to_hour(), (hhmm(10, 0)?, 0));
+    assert_eq!(hhmm(23, 30)?.snap_to_hour(), (UTCTimeOfDay::ZERO, 1));
+    assert_eq!(hhmmss(23, 29, 59)?.snap_to_hour(), (hhmm(23, 0)?, 0));
+    // test wrapping duration arithmetic
+    let two_hours = Duration::from_secs(2 * 60 * 60);
+    assert_eq!(hhmm(23, 0)? + two_hours, hhmm(1, 0)?);
+    assert_eq!(hhmm(1, 0)? - two_hours, hhmm(23, 0)?);
+    assert_eq!(hhmm(10, 0)? + two_hours, hhmm(12, 0)?);
+    assert_eq!(hhmm(10, 0)? - two_hours, hhmm(8, 0)?);
+    assert_eq!(
+        UTCTimeOfDay::MAX + Duration::from_nanos(1),
+        UTCTimeOfDay::ZERO
+    );
+    assert_eq!(
+        UTCTimeOfDay::ZERO - Duration::from_nanos(1),
+        UTCTimeOfDay::MAX
+    );
+    assert_eq!(
+        hhmm(10, 0)? + Duration::from_secs(SECONDS_PER_DAY),
+        hhmm(10, 0)?
+    );
+    assert_eq!(
+        hhmm(10, 0)? - Duration::from_secs(SECONDS_PER_DAY * 3),
+        hhmm(10, 0)?
+    );
+    assert_eq!(
+        hhmm(10, 0)? + Duration::MAX,
+        UTCTimeOfDay::try_from_hhmmss(17, 0, 15, 999_999_999)?
+    );
+    assert_eq!(
+        hhmm(10, 0)? - Duration::MAX,
+        UTCTimeOfDay::try_from_hhmmss(2, 59, 44, 1)?
+    );
+    let mut tod = hhmm(22, 30)?;
+    tod += two_hours;
+    assert_eq!(tod, hhmm(0, 30)?);
+    tod -= two_hours;
+    assert_eq!(tod, hhmm(22, 30)?);
+    // test bucket indexing
+    for buckets in [1, 2, 24, 1440, 86_400, 1_000_000, u32::MAX] {
+        assert_eq!(UTCTimeOfDay::ZERO.bucket_index(buckets), Some(0));
+        assert_eq!(UTCTimeOfDay::MAX.bucket_index(buckets), Some(buckets - 1));
+        assert!(tod_from_timestamp.bucket_index(buckets).unwrap() < buckets);
+    }
+    let tod = UTCTimeOfDay::try_from_hhmmss(10, 59, 59, 999_999_999)?;
+    assert_eq!(tod.bucket_index(24), Some(10));
+    assert_eq!(
+        UTCTimeOfDay::try_from_hhmmss(11, 0, 0, 0)?.bucket_index(24),
+        Some(11)
+    );
+    // zero buckets are rejected
+    assert_eq!(tod.bucket_index(0), None);
+    assert_eq!(UTCTimeOfDay::ZERO.bucket_index(0), None);
     // test display, debug, default, clone & copy, ord
     println!("{:?}:{tod_from_timestamp}", tod_from_timestamp);
     assert_eq!(UTCTimeOfDay::default().clone(), UTCTimeOfDay::ZERO);
@@ -399,14 +1366,14 @@ fn test_utc_tod() -> Result<(), UTCError> {
 #[test]
 fn test_time_serde() {
     let timestamp = UTCTimestamp::from_day(UTCDay::try_from_u64(19959).unwrap());
-    let v = serde_json::to_value(&timestamp).unwrap();
+    let v = serde_json::to_value(timestamp).unwrap();
     assert_eq!(timestamp, serde_json::from_value(v).unwrap());
 
     let day = UTCDay::try_from_u64(19959).unwrap();
-    let v = serde_json::to_value(&day).unwrap();
+    let v = serde_json::to_value(day).unwrap();
     assert_eq!(day, serde_json::from_value(v).unwrap());
 
     let time_of_day = UTCTimeOfDay::try_from_hhmmss(17, 50, 23, 0).unwrap();
-    let v = serde_json::to_value(&time_of_day).unwrap();
+    let v = serde_json::to_value(time_of_day).unwrap();
     assert_eq!(time_of_day, serde_json::from_value(v).unwrap());
 }